@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+/// Answer to a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Answer spanning multiple lines, e.g. letters drawn on a screen.
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Lines(_))
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Self {
+        Answer::Lines(lines)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_from_integers() {
+        assert_eq!(Answer::from(42u32), Answer::Integer(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::Integer(u64::MAX as i128));
+        assert_eq!(Answer::from(-3i64), Answer::Integer(-3));
+    }
+
+    #[test]
+    fn display_answers() {
+        assert_eq!(Answer::from(-3i64).to_string(), "-3");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(
+            Answer::from(vec!["#.#".to_string(), ".#.".to_string()]).to_string(),
+            "#.#\n.#."
+        );
+    }
+}
@@ -0,0 +1,299 @@
+use std::{error::Error, fmt::Display};
+
+/// Error produced when puzzle input does not match the expected format.
+///
+/// Remembers where in the input parsing failed, so that it can be shown to the user
+/// as a snippet of the offending line with a caret under the problematic column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    expected: String,
+    snippet: String,
+}
+
+impl ParseError {
+    /// Creates an error pointing at byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+
+        Self {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Creates an error pointing at `remaining`, which must be a suffix of `input`.
+    pub fn at_remaining(input: &str, remaining: &str, expected: impl Into<String>) -> Self {
+        let offset = input.len().saturating_sub(remaining.len());
+
+        Self::at_offset(input, offset, expected)
+    }
+
+    /// Creates an error pointing at zero-based `column` (in characters) of zero-based `line`.
+    pub fn at_position(
+        input: &str,
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+    ) -> Self {
+        let snippet = input.lines().nth(line).unwrap_or_default();
+
+        Self {
+            line: line + 1,
+            column: column + 1,
+            expected: expected.into(),
+            snippet: snippet.to_string(),
+        }
+    }
+
+    /// Moves the error `lines` lines down, for errors found in a part of a larger input.
+    pub fn with_line_offset(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// One-based line number of the error.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// One-based column (in characters) of the error.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Description of what was expected at the position of the error.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Contents of the offending line.
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+}
+
+/// Writes `snippet`, the contents of one-based `line`, with a caret under one-based `column`.
+fn write_snippet(
+    f: &mut std::fmt::Formatter<'_>,
+    line: usize,
+    column: usize,
+    snippet: &str,
+) -> std::fmt::Result {
+    let gutter = " ".repeat(line.to_string().len());
+    let padding: String = snippet
+        .chars()
+        .take(column - 1)
+        .map(|char| if char == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    writeln!(f, "{gutter} |")?;
+    writeln!(f, "{line} | {snippet}")?;
+    write!(f, "{gutter} | {padding}^")
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "expected {} at line {}, column {}",
+            self.expected, self.line, self.column
+        )?;
+        write_snippet(f, self.line, self.column, &self.snippet)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error produced when a part cannot be solved on the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The puzzle input does not match the expected format.
+    Parse(ParseError),
+    /// Something the puzzle relies on is missing from the input, e.g. a path to the goal.
+    NotFound { what: String },
+    /// The input breaks an assumption of the puzzle at one-based `line` and `column`.
+    Invalid {
+        line: usize,
+        column: usize,
+        reason: String,
+    },
+    /// A number on the way to the answer does not fit in its type.
+    Overflow { what: String },
+}
+
+impl SolveError {
+    pub fn not_found(what: impl Into<String>) -> Self {
+        Self::NotFound { what: what.into() }
+    }
+
+    /// Creates an error pointing at zero-based `column` (in characters) of zero-based `line`.
+    pub fn at_position(line: usize, column: usize, reason: impl Into<String>) -> Self {
+        Self::Invalid {
+            line: line + 1,
+            column: column + 1,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn overflow(what: impl Into<String>) -> Self {
+        Self::Overflow { what: what.into() }
+    }
+
+    /// Displays the error followed by the offending line of `input`, if it points at one.
+    pub fn in_input<'a>(&'a self, input: &'a str) -> impl Display + 'a {
+        InInput { error: self, input }
+    }
+
+    /// One-based line and column of the input the error points at, if any.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        match self {
+            SolveError::Parse(error) => Some((error.line(), error.column())),
+            SolveError::Invalid { line, column, .. } => Some((*line, *column)),
+            SolveError::NotFound { .. } | SolveError::Overflow { .. } => None,
+        }
+    }
+}
+
+struct InInput<'a> {
+    error: &'a SolveError,
+    input: &'a str,
+}
+
+impl Display for InInput<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.error {
+            SolveError::Invalid { line, column, .. } => {
+                let snippet = self.input.lines().nth(line - 1).unwrap_or_default();
+                writeln!(f, "{}", self.error)?;
+                write_snippet(f, *line, *column, snippet)
+            }
+            error => write!(f, "{error}"),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "{error}"),
+            SolveError::NotFound { what } => write!(f, "found no {what}"),
+            SolveError::Invalid {
+                line,
+                column,
+                reason,
+            } => write!(f, "{reason} at line {line}, column {column}"),
+            SolveError::Overflow { what } => write!(f, "{what} overflows"),
+        }
+    }
+}
+
+impl Error for SolveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SolveError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Game 1: 3 blue\nGame 2: x red\n";
+
+    #[test]
+    fn locate_offset() {
+        let error = ParseError::at_offset(INPUT, 23, "digit");
+
+        assert_eq!(error.line(), 2);
+        assert_eq!(error.column(), 9);
+        assert_eq!(error.expected(), "digit");
+        assert_eq!(error.snippet(), "Game 2: x red");
+    }
+
+    #[test]
+    fn locate_remaining() {
+        let error = ParseError::at_remaining(INPUT, &INPUT[23..], "digit");
+
+        assert_eq!(error, ParseError::at_offset(INPUT, 23, "digit"));
+        assert_eq!(error, ParseError::at_position(INPUT, 1, 8, "digit"));
+    }
+
+    #[test]
+    fn locate_end_of_input() {
+        let error = ParseError::at_offset(INPUT, INPUT.len(), "game");
+
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert_eq!(error.snippet(), "");
+    }
+
+    #[test]
+    fn display_snippet() {
+        let error = ParseError::at_offset(INPUT, 23, "digit");
+
+        assert_eq!(
+            error.to_string(),
+            "expected digit at line 2, column 9
+  |
+2 | Game 2: x red
+  |         ^"
+        );
+    }
+
+    #[test]
+    fn display_solve_errors() {
+        let invalid = SolveError::at_position(1, 8, "pipe leaving the map");
+        assert_eq!(invalid.position(), Some((2, 9)));
+        assert_eq!(
+            invalid.to_string(),
+            "pipe leaving the map at line 2, column 9"
+        );
+
+        assert_eq!(
+            SolveError::not_found("path to the goal").to_string(),
+            "found no path to the goal"
+        );
+        assert_eq!(SolveError::overflow("sum").to_string(), "sum overflows");
+
+        let parse = SolveError::from(ParseError::at_offset(INPUT, 23, "digit"));
+        assert_eq!(parse.position(), Some((2, 9)));
+    }
+
+    #[test]
+    fn display_solve_error_in_input() {
+        let error = SolveError::at_position(1, 8, "unknown color");
+
+        assert_eq!(
+            error.in_input(INPUT).to_string(),
+            "unknown color at line 2, column 9
+  |
+2 | Game 2: x red
+  |         ^"
+        );
+        assert_eq!(
+            SolveError::overflow("sum").in_input(INPUT).to_string(),
+            "sum overflows"
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Locating and reading puzzle inputs independently of the current working directory.
+//!
+//! Everything but [`normalize`] needs the `fs` feature.
+
+#[cfg(feature = "fs")]
+use std::{
+    env,
+    ffi::OsStr,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable naming a directory with inputs stored as `dayN.txt`.
+#[cfg(feature = "fs")]
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Path of the puzzle input of the day crate at `manifest_dir`.
+///
+/// Looks for `dayN.txt` in [`INPUT_DIR_VAR`] if it is set, and for `input.txt` next to
+/// the crate manifest otherwise.
+#[cfg(feature = "fs")]
+pub fn input_path(manifest_dir: impl AsRef<Path>) -> PathBuf {
+    let manifest_dir = manifest_dir.as_ref();
+
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(input_dir) => {
+            let day = manifest_dir.file_name().unwrap_or(OsStr::new("input"));
+            Path::new(&input_dir).join(day).with_extension("txt")
+        }
+        None => manifest_dir.join("input.txt"),
+    }
+}
+
+/// Reads and [normalizes](normalize) the input of the day crate at `manifest_dir`,
+/// or the one at `path` if it is given.
+///
+/// Day binaries call it as `load(env!("CARGO_MANIFEST_DIR"), env::args_os().nth(1))`.
+#[cfg(feature = "fs")]
+pub fn load(manifest_dir: impl AsRef<Path>, path: Option<impl AsRef<Path>>) -> io::Result<String> {
+    match path {
+        Some(path) => read(path),
+        None => read(input_path(manifest_dir)),
+    }
+}
+
+/// Reads the file at `path` and [normalizes](normalize) its contents.
+#[cfg(feature = "fs")]
+pub fn read(path: impl AsRef<Path>) -> io::Result<String> {
+    fs::read_to_string(path).map(|contents| normalize(&contents))
+}
+
+/// Strips the byte order mark and trailing newlines and converts CRLF line endings to LF,
+/// so that parsers only ever see `\n`.
+pub fn normalize(contents: &str) -> String {
+    contents
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings() {
+        assert_eq!(normalize("\u{feff}#.\r\n.#\r\n\r\n"), "#.\n.#");
+        assert_eq!(normalize("1 2\n3 4\n"), "1 2\n3 4");
+        assert_eq!(normalize("1 2\n\n3 4"), "1 2\n\n3 4");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn locate_input_next_to_manifest() {
+        if env::var_os(INPUT_DIR_VAR).is_some() {
+            return;
+        }
+
+        assert_eq!(
+            input_path("/aoc/day7"),
+            Path::new("/aoc/day7").join("input.txt")
+        );
+    }
+}
@@ -0,0 +1,10 @@
+mod answer;
+mod error;
+pub mod input;
+#[cfg(feature = "nom")]
+pub mod parse;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{ParseError, SolveError};
+pub use solution::Solution;
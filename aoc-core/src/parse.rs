@@ -0,0 +1,122 @@
+//! Glue between [`nom`] parsers and [`ParseError`].
+
+use nom::{
+    character::complete::line_ending,
+    combinator::cut,
+    error::{Error, ErrorKind},
+    Finish, IResult, Parser,
+};
+
+use crate::ParseError;
+
+impl ParseError {
+    /// Converts error returned by a [`nom`] parser that was run on `input`.
+    pub fn from_nom(input: &str, error: Error<&str>) -> Self {
+        Self::at_remaining(input, error.input, describe(error.code))
+    }
+}
+
+fn describe(kind: ErrorKind) -> String {
+    let expected = match kind {
+        ErrorKind::Tag => "literal text",
+        ErrorKind::Char => "specific character",
+        ErrorKind::OneOf => "one of allowed characters",
+        ErrorKind::Digit => "digit",
+        ErrorKind::Alpha => "letter",
+        ErrorKind::AlphaNumeric => "letter or digit",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::CrLf => "line ending",
+        ErrorKind::MapRes | ErrorKind::MapOpt => "valid value",
+        ErrorKind::Eof => "end of input",
+        kind => return kind.description().to_lowercase(),
+    };
+
+    expected.to_string()
+}
+
+/// Runs `parser` on the whole of `input`, allowing only trailing whitespace after it.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, T, Error<&'a str>>,
+) -> Result<T, ParseError> {
+    let (remaining, value) = parser
+        .parse(input)
+        .finish()
+        .map_err(|error| ParseError::from_nom(input, error))?;
+
+    if !remaining.trim().is_empty() {
+        return Err(ParseError::at_remaining(input, remaining, "end of input"));
+    }
+
+    Ok(value)
+}
+
+/// Parses consecutive lines with `parser`, until the end of input or an empty line.
+///
+/// Each line must be fully consumed by `parser`.
+///
+/// Unlike `separated_list1(line_ending, parser)`, a malformed line is reported as
+/// a failure at the position of the mistake instead of silently ending the list.
+pub fn lines<'a, T>(
+    mut parser: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    move |mut input| {
+        let mut items = Vec::new();
+
+        loop {
+            let (remaining, item) = cut(|input| parser.parse(input))(input)?;
+            items.push(item);
+
+            if remaining.is_empty() {
+                return Ok((remaining, items));
+            }
+
+            let (next, _) = cut(line_ending)(remaining)?;
+            if next.is_empty() || line_ending::<_, Error<&str>>(next).is_ok() {
+                return Ok((remaining, items));
+            }
+
+            input = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::digit1, combinator::map_res};
+
+    use super::*;
+
+    fn number(input: &str) -> IResult<&str, u32> {
+        map_res(digit1, str::parse)(input)
+    }
+
+    #[test]
+    fn parse_all_lines() {
+        assert_eq!(
+            parse_all("1\n22\n333\n", lines(number)),
+            Ok(vec![1, 22, 333])
+        );
+    }
+
+    #[test]
+    fn lines_stop_at_empty_line() {
+        let (remaining, numbers) = lines(number)("1\n2\n\n3").unwrap();
+
+        assert_eq!(numbers, vec![1, 2]);
+        assert_eq!(remaining, "\n\n3");
+    }
+
+    #[test]
+    fn report_malformed_line() {
+        let error = parse_all("1\n2x\n3", lines(number)).unwrap_err();
+
+        assert_eq!((error.line(), error.column()), (2, 2));
+        assert_eq!(error.expected(), "line ending");
+
+        let error = parse_all("1\nx\n3", lines(number)).unwrap_err();
+
+        assert_eq!((error.line(), error.column()), (2, 1));
+        assert_eq!(error.expected(), "digit");
+    }
+}
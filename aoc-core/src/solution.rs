@@ -0,0 +1,15 @@
+use crate::{Answer, ParseError, SolveError};
+
+/// Common interface implemented by every day, so that tooling can solve any of them uniformly.
+///
+/// The puzzle input is parsed once with [`Solution::parse`] and the parsed model is then shared
+/// by both parts, which return a [`SolveError`] when the input has no answer.
+pub trait Solution {
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_1(input: &Self::Input<'_>) -> Result<Answer, SolveError>;
+
+    fn part_2(input: &Self::Input<'_>) -> Result<Answer, SolveError>;
+}
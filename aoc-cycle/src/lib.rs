@@ -0,0 +1,256 @@
+//! Cycle detection in sequences `x0, step(x0), step(step(x0)), ...`.
+//!
+//! States are compared with [`Eq`] in full, so unlike remembering hashes of seen states,
+//! a collision can never produce a wrong cycle.
+
+/// Cycle in a sequence of states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Index of the first state that is part of the cycle.
+    pub start: usize,
+    /// Number of steps after which the states repeat.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Smallest index of a state equal to the one after `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Algorithm to find a cycle with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Algorithm {
+    /// Floyd's tortoise and hare, see [`floyd`].
+    Floyd,
+    /// Brent's, see [`brent`].
+    #[default]
+    Brent,
+}
+
+/// Finds the cycle with `algorithm`.
+///
+/// Does not terminate if the sequence never repeats.
+pub fn find_cycle<T: Clone + Eq>(
+    initial: &T,
+    step: impl FnMut(&T) -> T,
+    algorithm: Algorithm,
+) -> Cycle {
+    match algorithm {
+        Algorithm::Floyd => floyd(initial, step),
+        Algorithm::Brent => brent(initial, step),
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare algorithm.
+///
+/// Does not terminate if the sequence never repeats.
+pub fn floyd<T: Clone + Eq>(initial: &T, step: impl FnMut(&T) -> T) -> Cycle {
+    floyd_within(initial, step, usize::MAX).expect("sequence never repeats")
+}
+
+/// Runs Floyd's algorithm, giving up once the tortoise was moved `max_steps` steps.
+fn floyd_within<T: Clone + Eq>(
+    initial: &T,
+    mut step: impl FnMut(&T) -> T,
+    max_steps: usize,
+) -> Option<Cycle> {
+    let mut steps = 1;
+    let mut tortoise = step(initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        if steps >= max_steps {
+            return None;
+        }
+
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+        steps += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// Finds the cycle with Brent's algorithm, which takes fewer steps than [`floyd`].
+///
+/// Does not terminate if the sequence never repeats.
+pub fn brent<T: Clone + Eq>(initial: &T, step: impl FnMut(&T) -> T) -> Cycle {
+    brent_within(initial, step, usize::MAX).expect("sequence never repeats")
+}
+
+/// Runs Brent's algorithm, giving up once the sequence was followed for `max_steps` steps.
+fn brent_within<T: Clone + Eq>(
+    initial: &T,
+    mut step: impl FnMut(&T) -> T,
+    max_steps: usize,
+) -> Option<Cycle> {
+    let mut power = 1;
+    let mut length = 1;
+    let mut steps = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if steps >= max_steps {
+            return None;
+        }
+
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+        steps += 1;
+    }
+
+    tortoise = initial.clone();
+    hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// Returns the state after applying `step` to `initial` `n` times, skipping over whole
+/// cycles of the sequence once `algorithm` finds them.
+pub fn state_after_n_steps<T: Clone + Eq>(
+    initial: T,
+    mut step: impl FnMut(&T) -> T,
+    n: usize,
+    algorithm: Algorithm,
+) -> T {
+    let cycle = match algorithm {
+        Algorithm::Floyd => floyd_within(&initial, &mut step, n),
+        Algorithm::Brent => brent_within(&initial, &mut step, n),
+    };
+    let steps = match cycle {
+        Some(cycle) => cycle.reduce(n),
+        None => n,
+    };
+
+    (0..steps).fold(initial, |state, _| step(&state))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const ALGORITHMS: [Algorithm; 2] = [Algorithm::Floyd, Algorithm::Brent];
+
+    /// Finds the cycle by remembering every state.
+    fn brute_force(initial: u64, step: impl Fn(&u64) -> u64) -> Cycle {
+        let mut seen = HashMap::new();
+        let mut state = initial;
+        for index in 0.. {
+            if let Some(start) = seen.insert(state, index) {
+                return Cycle {
+                    start,
+                    length: index - start,
+                };
+            }
+            state = step(&state);
+        }
+
+        unreachable!()
+    }
+
+    #[test]
+    fn cycle_with_tail() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+        let step = |&state: &u64| if state == 4 { 2 } else { state + 1 };
+        let expected = Cycle {
+            start: 2,
+            length: 3,
+        };
+
+        assert_eq!(expected.reduce(1), 1);
+        assert_eq!(expected.reduce(1_000_000_000), 4);
+        for algorithm in ALGORITHMS {
+            assert_eq!(find_cycle(&0, step, algorithm), expected);
+            assert_eq!(state_after_n_steps(0, step, 1_000_000_000, algorithm), 4);
+        }
+    }
+
+    #[test]
+    fn fixed_point() {
+        let step = |&state: &u64| state.saturating_sub(1);
+
+        for algorithm in ALGORITHMS {
+            assert_eq!(
+                find_cycle(&3, step, algorithm),
+                Cycle {
+                    start: 3,
+                    length: 1
+                }
+            );
+            assert_eq!(state_after_n_steps(3, step, usize::MAX, algorithm), 0);
+        }
+    }
+
+    #[test]
+    fn stop_before_cycle_is_found() {
+        // Never repeats, but only a few steps are requested.
+        for algorithm in ALGORITHMS {
+            assert_eq!(
+                state_after_n_steps(0u64, |&state| state + 1, 5, algorithm),
+                5
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(initial in 0u64..1000, modulus in 1u64..1000, c in 0u64..1000) {
+            let step = |&state: &u64| (state * state + c) % modulus;
+            let expected = brute_force(initial, step);
+
+            for algorithm in ALGORITHMS {
+                prop_assert_eq!(find_cycle(&initial, step, algorithm), expected);
+            }
+        }
+
+        #[test]
+        fn skips_cycles(initial in 0u64..1000, modulus in 1u64..100, n in 0usize..2000) {
+            let step = |&state: &u64| (state * 7 + 3) % modulus + state % 2;
+            let simulated = (0..n).fold(initial, |state, _| step(&state));
+
+            for algorithm in ALGORITHMS {
+                prop_assert_eq!(state_after_n_steps(initial, step, n, algorithm), simulated);
+            }
+        }
+    }
+}
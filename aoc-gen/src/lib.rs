@@ -0,0 +1,95 @@
+//! Helpers for generating random puzzle inputs.
+//!
+//! Generators take any [`Rng`], and [`rng`] creates the one used by the runner, whose output
+//! for a given seed does not change between platforms or releases of `rand`.
+
+use rand::distributions::{Distribution, WeightedIndex};
+pub use rand::{self, seq::SliceRandom, Rng};
+use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
+
+/// Random number generator with reproducible output for a given seed.
+pub type GenRng = ChaCha8Rng;
+
+pub fn rng(seed: u64) -> GenRng {
+    GenRng::seed_from_u64(seed)
+}
+
+/// Picks one of `choices`, each with probability proportional to its weight.
+///
+/// Panics if `choices` is empty or all weights are zero.
+pub fn weighted<T: Copy>(rng: &mut (impl Rng + ?Sized), choices: &[(T, u32)]) -> T {
+    let index = WeightedIndex::new(choices.iter().map(|&(_, weight)| weight))
+        .expect("at least one choice has a positive weight");
+
+    choices[index.sample(rng)].0
+}
+
+/// Lines of a `width` by `height` grid of characters drawn by `tile`, without a trailing
+/// line break, like puzzle inputs once they are loaded.
+pub fn grid(
+    width: usize,
+    height: usize,
+    rng: &mut impl Rng,
+    mut tile: impl FnMut(&mut dyn rand::RngCore) -> char,
+) -> String {
+    let mut output = String::with_capacity((width + 1) * height);
+    for y in 0..height {
+        if y > 0 {
+            output.push('\n');
+        }
+        output.extend((0..width).map(|_| tile(rng)));
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible_output() {
+        let draw = |seed| {
+            let mut rng = rng(seed);
+            (0..10)
+                .map(|_| rng.gen_range(0..1000))
+                .collect::<Vec<u32>>()
+        };
+
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+    }
+
+    #[test]
+    fn weighted_choices() {
+        let mut rng = rng(0);
+        let choices = [('a', 0), ('b', 3), ('c', 1)];
+        let picks = (0..1000)
+            .map(|_| weighted(&mut rng, &choices))
+            .collect::<Vec<_>>();
+
+        assert!(!picks.contains(&'a'));
+        let b = picks.iter().filter(|&&pick| pick == 'b').count();
+        assert!((650..850).contains(&b), "{b}");
+    }
+
+    #[test]
+    fn grid_lines() {
+        let grid = grid(
+            3,
+            2,
+            &mut rng(0),
+            |rng| {
+                if rng.gen_bool(0.5) {
+                    '#'
+                } else {
+                    '.'
+                }
+            },
+        );
+
+        let lines = grid.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| line.len() == 3));
+    }
+}
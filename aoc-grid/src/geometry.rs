@@ -0,0 +1,245 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// One of the four cardinal directions, with north pointing towards smaller `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting with north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn all() -> impl Iterator<Item = Direction> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+
+    /// Unit vector pointing in this direction.
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::North => Vec2::new(0, -1),
+            Direction::East => Vec2::new(1, 0),
+            Direction::South => Vec2::new(0, 1),
+            Direction::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+/// Signed offset between two positions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Length of the vector when moving only horizontally and vertically.
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Length of the vector when diagonal moves are allowed.
+    pub fn chebyshev(self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Self::Output {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Self::Output {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: isize) -> Self::Output {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Position of a tile, with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Moves by `offset`, or returns `None` if either coordinate would become negative.
+    pub fn checked_add(self, offset: Vec2) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+
+    /// Moves by `offset`, or returns `None` if the result would lie outside of
+    /// a `width` by `height` rectangle.
+    pub fn checked_add_within(self, offset: Vec2, width: usize, height: usize) -> Option<Self> {
+        self.checked_add(offset)
+            .filter(|pos| pos.x < width && pos.y < height)
+    }
+
+    /// Moves one tile in `direction`, or returns `None` if the result would lie outside of
+    /// a `width` by `height` rectangle.
+    pub fn checked_step(self, direction: Direction, width: usize, height: usize) -> Option<Self> {
+        self.checked_add_within(direction.offset(), width, height)
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        (self - other).manhattan()
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> usize {
+        (self - other).chebyshev()
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl Sub for Pos {
+    type Output = Vec2;
+
+    fn sub(self, other: Pos) -> Self::Output {
+        Vec2::new(
+            self.x as isize - other.x as isize,
+            self.y as isize - other.y as isize,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate_directions() {
+        for direction in Direction::all() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(
+                direction.offset() + direction.opposite().offset(),
+                Vec2::ZERO
+            );
+        }
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn step_within_bounds() {
+        let corner = Pos::new(0, 0);
+
+        assert_eq!(corner.checked_step(Direction::North, 3, 2), None);
+        assert_eq!(corner.checked_step(Direction::West, 3, 2), None);
+        assert_eq!(
+            corner.checked_step(Direction::South, 3, 2),
+            Some(Pos::new(0, 1))
+        );
+        assert_eq!(Pos::new(0, 1).checked_step(Direction::South, 3, 2), None);
+        assert_eq!(Pos::new(2, 0).checked_step(Direction::East, 3, 2), None);
+        assert_eq!(
+            Pos::new(2, 1).checked_add(Vec2::new(-2, -1)),
+            Some(Pos::new(0, 0))
+        );
+    }
+
+    #[test]
+    fn measure_distances() {
+        let a = Pos::new(1, 6);
+        let b = Pos::new(5, 11);
+
+        assert_eq!(b - a, Vec2::new(4, 5));
+        assert_eq!(a.manhattan_distance(b), 9);
+        assert_eq!(b.manhattan_distance(a), 9);
+        assert_eq!(a.chebyshev_distance(b), 5);
+        assert_eq!((Vec2::new(2, -3) * 2).chebyshev(), 6);
+    }
+}
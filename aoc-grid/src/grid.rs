@@ -0,0 +1,348 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    slice::ChunksExact,
+    str::FromStr,
+};
+
+use aoc_core::ParseError;
+
+use crate::{
+    geometry::{Direction, Pos},
+    iter::{Column, IterIndices, Neighbors},
+};
+
+/// Rectangular grid of tiles stored in row-major order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub width: usize,
+    pub height: usize,
+    tiles: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, tiles: impl IntoIterator<Item = T>) -> Self {
+        let tiles: Vec<_> = tiles.into_iter().collect();
+
+        assert_eq!(tiles.len(), width * height);
+
+        Self {
+            width,
+            height,
+            tiles,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let tiles = (0..height).flat_map(|y| (0..width).map(move |x| (x, y)));
+
+        Self::new(width, height, tiles.map(|(x, y)| f(x, y)))
+    }
+
+    pub fn filled(width: usize, height: usize, tile: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![tile; width * height])
+    }
+
+    #[inline]
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Returns the tile at `(x, y)`, or `None` if it lies outside of the grid.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            self.tiles.get(x + y * self.width)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            self.tiles.get_mut(x + y * self.width)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `y`-th row of the grid.
+    ///
+    /// Panics if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is out of bounds");
+
+        &self.tiles[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.tiles.chunks_exact(self.width.max(1))
+    }
+
+    /// Returns the `x`-th column of the grid, from top to bottom.
+    ///
+    /// Panics if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> Column<'_, T> {
+        assert!(x < self.width, "column {x} is out of bounds");
+
+        Column::new(&self.tiles[x..], self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = Column<'_, T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.tiles.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.tiles.iter_mut()
+    }
+
+    pub fn iter_indices(&self) -> IterIndices {
+        IterIndices::new(self.width, self.height)
+    }
+
+    /// Iterates over positions directly above, right, below and left of `(x, y)`
+    /// that lie inside of the grid.
+    pub fn neighbors4(&self, x: usize, y: usize) -> Neighbors {
+        Neighbors::orthogonal(x, y, self.width, self.height)
+    }
+
+    /// Iterates over all positions surrounding `(x, y)`, including diagonals, in row-major order.
+    pub fn neighbors8(&self, x: usize, y: usize) -> Neighbors {
+        Neighbors::surrounding(x, y, self.width, self.height)
+    }
+
+    /// Moves from `pos` one tile in `direction`, or returns `None` when leaving the grid.
+    #[inline]
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        pos.checked_step(direction, self.width, self.height)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.tiles.iter().map(f))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `(x, y)` lies outside of the grid.
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("position ({x}, {y}) is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    /// Panics if `(x, y)` lies outside of the grid.
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("position ({x}, {y}) is out of bounds"))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `pos` lies outside of the grid.
+    #[inline]
+    fn index(&self, pos: Pos) -> &Self::Output {
+        &self[(pos.x, pos.y)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    /// Panics if `pos` lies outside of the grid.
+    #[inline]
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        &mut self[(pos.x, pos.y)]
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut tiles = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let row_start = tiles.len();
+            for (x, char) in line.chars().enumerate() {
+                let tile = T::try_from(char).map_err(|_| {
+                    ParseError::at_position(input, y, x, format!("valid tile, found {char:?}"))
+                })?;
+                tiles.push(tile);
+            }
+
+            let row_width = tiles.len() - row_start;
+            if y == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(ParseError::at_position(
+                    input,
+                    y,
+                    row_width.min(width),
+                    format!("row of {width} tiles"),
+                ));
+            }
+
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(ParseError::at_position(input, 0, 0, "at least one tile"));
+        }
+
+        Ok(Self::new(width, height, tiles))
+    }
+}
+
+impl<T: Clone + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{}", tile.clone().into())?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = &'static str;
+
+        fn try_from(char: char) -> Result<Self, Self::Error> {
+            match char {
+                '.' => Ok(Tile::Empty),
+                '#' => Ok(Tile::Wall),
+                _ => Err("Invalid character"),
+            }
+        }
+    }
+
+    impl From<Tile> for char {
+        fn from(tile: Tile) -> Self {
+            match tile {
+                Tile::Empty => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    #[test]
+    fn grid_returns_correct_values() {
+        let cells = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let grid = Grid::new(3, 3, cells);
+
+        assert_eq!(grid[(0, 0)], 1);
+        assert_eq!(grid[(1, 2)], 8);
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get(2, 2), Some(&9));
+    }
+
+    #[test]
+    fn get_does_not_wrap_across_rows() {
+        let grid = Grid::new(3, 2, 0..6);
+
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    fn step_and_index_by_position() {
+        let grid = Grid::new(3, 2, 0..6);
+
+        let pos = grid.step(Pos::new(1, 0), Direction::South).unwrap();
+        assert_eq!(grid[pos], 4);
+        assert_eq!(grid.step(pos, Direction::South), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds_panics() {
+        let grid = Grid::new(2, 2, 0..4);
+
+        let _ = grid[(2, 0)];
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::new(3, 2, 0..6);
+
+        assert_eq!(grid.row(1), &[3, 4, 5]);
+        assert_eq!(grid.rows().collect_vec(), vec![&[0, 1, 2], &[3, 4, 5]]);
+        assert_eq!(grid.column(1).copied().collect_vec(), vec![1, 4]);
+        assert_eq!(grid.column(2).rev().copied().collect_vec(), vec![5, 2]);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new(3, 3, 0..9);
+
+        assert_eq!(
+            grid.neighbors4(1, 1).collect_vec(),
+            vec![(1, 0), (2, 1), (1, 2), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4(0, 0).collect_vec(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(
+            grid.neighbors8(2, 0).collect_vec(),
+            vec![(1, 0), (1, 1), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let input = "..#\n#..\n";
+
+        let grid: Grid<Tile> = input.parse().unwrap();
+
+        assert_eq!(grid.width, 3);
+        assert_eq!(grid.height, 2);
+        assert_eq!(grid[(2, 0)], Tile::Wall);
+        assert_eq!(grid[(0, 1)], Tile::Wall);
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = "".parse::<Grid<Tile>>().unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+        assert_eq!(error.expected(), "at least one tile");
+
+        let error = "..\n...".parse::<Grid<Tile>>().unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 3));
+        assert_eq!(error.expected(), "row of 2 tiles");
+
+        let error = "..\n.x".parse::<Grid<Tile>>().unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 2));
+        assert_eq!(error.expected(), "valid tile, found 'x'");
+        assert_eq!(error.snippet(), ".x");
+    }
+}
@@ -0,0 +1,201 @@
+use std::iter::{FusedIterator, StepBy};
+
+/// Iterator over `(x, y)` positions of a grid in row-major order.
+pub struct IterIndices {
+    front: usize,
+    back: usize,
+    width: usize,
+}
+
+impl IterIndices {
+    pub(crate) fn new(width: usize, height: usize) -> Self {
+        Self {
+            front: 0,
+            back: width * height,
+            width,
+        }
+    }
+}
+
+impl Iterator for IterIndices {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let idx = self.front;
+        self.front += 1;
+
+        Some((idx % self.width, idx / self.width))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for IterIndices {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some((self.back % self.width, self.back / self.width))
+    }
+}
+
+impl ExactSizeIterator for IterIndices {}
+
+impl FusedIterator for IterIndices {}
+
+/// Iterator over tiles in a single column of a grid.
+pub struct Column<'a, T> {
+    tiles: StepBy<std::slice::Iter<'a, T>>,
+}
+
+impl<'a, T> Column<'a, T> {
+    pub(crate) fn new(tiles: &'a [T], width: usize) -> Self {
+        Self {
+            tiles: tiles.iter().step_by(width),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Column<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tiles.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.tiles.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Column<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.tiles.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Column<'a, T> {}
+
+const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+const SURROUNDING_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Iterator over in-bounds neighbours of a grid position.
+pub struct Neighbors {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    offsets: std::slice::Iter<'static, (isize, isize)>,
+}
+
+impl Neighbors {
+    pub(crate) fn orthogonal(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            offsets: ORTHOGONAL_OFFSETS.iter(),
+        }
+    }
+
+    pub(crate) fn surrounding(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            offsets: SURROUNDING_OFFSETS.iter(),
+        }
+    }
+}
+
+impl Iterator for Neighbors {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &(dx, dy) in self.offsets.by_ref() {
+            let x = self.x.checked_add_signed(dx).filter(|&x| x < self.width);
+            let y = self.y.checked_add_signed(dy).filter(|&y| y < self.height);
+
+            if let (Some(x), Some(y)) = (x, y) {
+                return Some((x, y));
+            }
+        }
+
+        None
+    }
+}
+
+impl FusedIterator for Neighbors {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Grid;
+
+    #[test]
+    fn test_iterator() {
+        let tiles = vec![0; 4];
+        let grid = Grid::new(2, 2, tiles);
+
+        let mut iter = grid.iter_indices();
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.next(), Some((1, 0)));
+        assert_eq!(iter.next(), Some((0, 1)));
+        assert_eq!(iter.next(), Some((1, 1)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_back_iterator() {
+        let tiles = vec![0; 4];
+        let grid = Grid::new(2, 2, tiles);
+
+        let mut iter = grid.iter_indices();
+        assert_eq!(iter.next_back(), Some((1, 1)));
+        assert_eq!(iter.next_back(), Some((0, 1)));
+        assert_eq!(iter.next_back(), Some((1, 0)));
+        assert_eq!(iter.next_back(), Some((0, 0)));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_double_endediterator() {
+        let tiles = vec![0; 4];
+        let grid = Grid::new(2, 2, tiles);
+
+        let mut iter = grid.iter_indices();
+        assert_eq!(iter.next(), Some((0, 0)));
+        assert_eq!(iter.next_back(), Some((1, 1)));
+        assert_eq!(iter.next(), Some((1, 0)));
+        assert_eq!(iter.next_back(), Some((0, 1)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_empty_iterator() {
+        let grid = Grid::<u8>::new(0, 0, []);
+
+        assert_eq!(grid.iter_indices().next(), None);
+    }
+}
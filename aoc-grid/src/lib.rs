@@ -0,0 +1,7 @@
+pub mod geometry;
+mod grid;
+mod iter;
+
+pub use geometry::{Direction, Pos, Vec2};
+pub use grid::Grid;
+pub use iter::{Column, IterIndices, Neighbors};
@@ -0,0 +1,111 @@
+use std::{fmt::Display, ops::Range};
+
+/// Half-open range of integers `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// Creates `start..end`, which is empty if `end <= start`.
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the interval of `len` integers starting at `start`.
+    pub const fn from_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Number of integers in the interval.
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.start.abs_diff(self.end)
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether the intervals have at least one integer in common.
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Integers in both intervals, possibly none.
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Splits the interval into integers below `at` and the rest; either part may be empty.
+    pub fn split_at(&self, at: i64) -> (Interval, Interval) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+
+        (
+            Interval::new(self.start, at),
+            Interval::new(at, self.end.max(at)),
+        )
+    }
+
+    /// Moves every integer of the interval by `offset`.
+    pub fn translate(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl From<Interval> for Range<i64> {
+    fn from(interval: Interval) -> Self {
+        interval.start..interval.end
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_properties() {
+        let interval = Interval::from_len(10, 5);
+
+        assert_eq!(interval, Interval::new(10, 15));
+        assert_eq!(interval.len(), 5);
+        assert!(interval.contains(10));
+        assert!(!interval.contains(15));
+        assert!(Interval::new(3, 3).is_empty());
+        assert_eq!(Interval::new(5, 2).len(), 0);
+    }
+
+    #[test]
+    fn intersect_and_split() {
+        let a = Interval::new(0, 10);
+        let b = Interval::new(5, 20);
+
+        assert_eq!(a.intersection(&b), Interval::new(5, 10));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&Interval::new(10, 12)));
+        assert_eq!(a.split_at(4), (Interval::new(0, 4), Interval::new(4, 10)));
+        assert!(a.split_at(-5).0.is_empty());
+        assert!(a.split_at(50).1.is_empty());
+        assert_eq!(a.translate(-3), Interval::new(-3, 7));
+    }
+}
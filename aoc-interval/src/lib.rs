@@ -0,0 +1,7 @@
+mod interval;
+mod range_map;
+mod set;
+
+pub use interval::Interval;
+pub use range_map::RangeMap;
+pub use set::IntervalSet;
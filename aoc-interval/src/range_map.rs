@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+use crate::{Interval, IntervalSet};
+
+/// Piecewise translation of integers: values inside a source interval are moved by that
+/// interval's offset, all other values map to themselves.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Start of each source interval mapped to its end and offset.
+    ranges: BTreeMap<i64, (i64, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps every value of `source` to itself plus `offset`.
+    ///
+    /// Returns `false` and leaves the map unchanged if `source` overlaps a source interval
+    /// that was inserted before.
+    pub fn insert(&mut self, source: Interval, offset: i64) -> bool {
+        if source.is_empty() {
+            return true;
+        }
+
+        if self.sources(source).next().is_some() {
+            return false;
+        }
+
+        self.ranges.insert(source.start, (source.end, offset));
+        true
+    }
+
+    pub fn get(&self, key: i64) -> i64 {
+        match self.ranges.range(..=key).next_back() {
+            Some((_, &(end, offset))) if key < end => key + offset,
+            _ => key,
+        }
+    }
+
+    /// Maps every value of `keys`, translating whole intervals at once.
+    pub fn map_ranges(&self, keys: &IntervalSet) -> IntervalSet {
+        let mut values = IntervalSet::new();
+        for interval in keys.iter() {
+            let mut rest = interval;
+            for (source, offset) in self.sources(interval) {
+                let (unmapped, mapped) = rest.split_at(source.start);
+                let (mapped, after) = mapped.split_at(source.end);
+                values.insert(unmapped);
+                values.insert(mapped.translate(offset));
+                rest = after;
+            }
+            values.insert(rest);
+        }
+
+        values
+    }
+
+    /// Source intervals with their offsets, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = (Interval, i64)> + '_ {
+        self.ranges
+            .iter()
+            .map(|(&start, &(end, offset))| (Interval::new(start, end), offset))
+    }
+
+    /// Source intervals overlapping `keys`, in increasing order.
+    fn sources(&self, keys: Interval) -> impl Iterator<Item = (Interval, i64)> + '_ {
+        let before = self
+            .ranges
+            .range(..keys.start)
+            .next_back()
+            .filter(|(_, &(end, _))| !keys.is_empty() && end > keys.start);
+        let within = self.ranges.range(keys.start..keys.end.max(keys.start));
+
+        before
+            .into_iter()
+            .chain(within)
+            .map(|(&start, &(end, offset))| (Interval::new(start, end), offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn example() -> RangeMap {
+        // Seed-to-soil map of the 2023 day 5 example.
+        let mut map = RangeMap::new();
+        assert!(map.insert(Interval::from_len(98, 2), 50 - 98));
+        assert!(map.insert(Interval::from_len(50, 48), 52 - 50));
+        map
+    }
+
+    #[test]
+    fn get_values() {
+        let map = example();
+
+        assert_eq!(map.get(49), 49);
+        assert_eq!(map.get(50), 52);
+        assert_eq!(map.get(97), 99);
+        assert_eq!(map.get(98), 50);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(100), 100);
+    }
+
+    #[test]
+    fn reject_overlapping_sources() {
+        let mut map = example();
+
+        assert!(!map.insert(Interval::new(40, 51), 1));
+        assert!(!map.insert(Interval::new(99, 101), 1));
+        assert!(map.insert(Interval::new(100, 101), 1));
+        assert_eq!(map.iter().count(), 3);
+    }
+
+    #[test]
+    fn map_whole_ranges() {
+        let map = example();
+        let keys = IntervalSet::from_iter([Interval::from_len(79, 14), Interval::from_len(55, 13)]);
+
+        assert_eq!(
+            map.map_ranges(&keys),
+            IntervalSet::from_iter([Interval::from_len(81, 14), Interval::from_len(57, 13)])
+        );
+
+        // 50..98 and 98..100 swap places, so the whole range maps onto itself.
+        let keys = IntervalSet::from(Interval::new(45, 105));
+        assert_eq!(map.map_ranges(&keys), keys);
+        let keys = IntervalSet::from(Interval::new(96, 100));
+        assert_eq!(
+            map.map_ranges(&keys),
+            IntervalSet::from_iter([Interval::new(50, 52), Interval::new(98, 100)])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn matches_mapping_each_key(
+            sources in prop::collection::vec((-50i64..50, 1i64..20, -30i64..30), 0..6),
+            keys in prop::collection::vec((-60i64..60, 0i64..30), 0..6),
+        ) {
+            let mut map = RangeMap::new();
+            for (start, len, offset) in sources {
+                map.insert(Interval::from_len(start, len), offset);
+            }
+            let keys = keys
+                .into_iter()
+                .map(|(start, len)| Interval::from_len(start, len))
+                .collect::<IntervalSet>();
+
+            let expected = keys
+                .iter()
+                .flat_map(|interval| interval.start..interval.end)
+                .map(|key| map.get(key))
+                .collect::<BTreeSet<_>>();
+            let mapped = map
+                .map_ranges(&keys)
+                .iter()
+                .flat_map(|interval| interval.start..interval.end)
+                .collect::<BTreeSet<_>>();
+
+            prop_assert_eq!(mapped, expected);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use std::collections::BTreeMap;
+
+use crate::Interval;
+
+/// Set of integers stored as disjoint intervals.
+///
+/// Overlapping or adjacent intervals are merged on insertion, so every integer is covered by
+/// at most one stored interval and lookups take `O(log n)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    /// Start of each interval mapped to its end.
+    intervals: BTreeMap<i64, i64>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every integer of `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let mut merged = interval;
+        if let Some((&start, &end)) = self.intervals.range(..=merged.start).next_back() {
+            if end >= merged.start {
+                merged.start = start;
+                merged.end = merged.end.max(end);
+            }
+        }
+
+        let touched = self
+            .intervals
+            .range(merged.start..=merged.end)
+            .map(|(&start, &end)| (start, end))
+            .collect::<Vec<_>>();
+        for (start, end) in touched {
+            self.intervals.remove(&start);
+            merged.end = merged.end.max(end);
+        }
+
+        self.intervals.insert(merged.start, merged.end);
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.intervals
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &end)| value < end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.iter().map(|interval| interval.len()).sum()
+    }
+
+    /// Smallest integer in the set.
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.keys().next().copied()
+    }
+
+    /// Largest integer in the set.
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.values().next_back().map(|end| end - 1)
+    }
+
+    /// Disjoint intervals of the set in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals
+            .iter()
+            .map(|(&start, &end)| Interval::new(start, end))
+    }
+
+    /// Stored intervals that have at least one integer in common with `interval`, in
+    /// increasing order.
+    pub fn overlapping(&self, interval: Interval) -> impl Iterator<Item = Interval> + '_ {
+        let before = self
+            .intervals
+            .range(..interval.start)
+            .next_back()
+            .filter(|(_, &end)| !interval.is_empty() && end > interval.start);
+        let within = self
+            .intervals
+            .range(interval.start..interval.end.max(interval.start));
+
+        before
+            .into_iter()
+            .chain(within)
+            .map(|(&start, &end)| Interval::new(start, end))
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter()
+            .flat_map(|interval| {
+                other
+                    .overlapping(interval)
+                    .map(move |overlap| overlap.intersection(&interval))
+            })
+            .collect()
+    }
+
+    /// Integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = IntervalSet::new();
+        for interval in self.iter() {
+            let mut rest = interval;
+            for removed in other.overlapping(interval) {
+                let (below, above) = rest.split_at(removed.start);
+                difference.insert(below);
+                rest = above.split_at(removed.end).1;
+            }
+            difference.insert(rest);
+        }
+
+        difference
+    }
+
+    /// Splits the set into integers below `at` and the rest.
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        let mut below = IntervalSet::new();
+        let mut above = IntervalSet::new();
+        for interval in self.iter() {
+            let (low, high) = interval.split_at(at);
+            below.insert(low);
+            above.insert(high);
+        }
+
+        (below, above)
+    }
+
+    /// Moves every integer of the set by `offset`.
+    pub fn translate(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|(&start, &end)| (start + offset, end + offset))
+                .collect(),
+        }
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<T: IntoIterator<Item = Interval>>(&mut self, intervals: T) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(intervals: T) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self::from_iter([interval])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn integers(set: &IntervalSet) -> BTreeSet<i64> {
+        set.iter()
+            .flat_map(|interval| interval.start..interval.end)
+            .collect()
+    }
+
+    fn intervals() -> impl Strategy<Value = Vec<(i64, i64)>> {
+        prop::collection::vec((-50i64..50, 0i64..20), 0..8).prop_map(|intervals| {
+            intervals
+                .into_iter()
+                .map(|(start, len)| (start, start + len))
+                .collect()
+        })
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent() {
+        let set = set(&[(5, 10), (0, 2), (10, 12), (1, 3), (20, 20), (8, 9)]);
+
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Interval::new(0, 3), Interval::new(5, 12)]
+        );
+        assert_eq!(set.len(), 10);
+        assert_eq!((set.min(), set.max()), (Some(0), Some(11)));
+        assert!(set.contains(2));
+        assert!(!set.contains(3));
+        assert!(set.contains(11));
+        assert!(!set.contains(12));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 30)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 30)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20)]));
+        assert_eq!(
+            a.split_at(22),
+            (set(&[(0, 10), (20, 22)]), set(&[(22, 30)]))
+        );
+        assert_eq!(a.translate(-5), set(&[(-5, 5), (15, 25)]));
+    }
+
+    #[test]
+    fn overlapping_intervals() {
+        let a = set(&[(0, 10), (20, 30), (40, 50)]);
+
+        assert_eq!(
+            a.overlapping(Interval::new(9, 40)).collect::<Vec<_>>(),
+            vec![Interval::new(0, 10), Interval::new(20, 30)]
+        );
+        assert_eq!(a.overlapping(Interval::new(10, 20)).count(), 0);
+        assert_eq!(a.overlapping(Interval::new(25, 25)).count(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_sets_of_integers(a in intervals(), b in intervals(), at in -60i64..80, offset in -100i64..100) {
+            let (a, b) = (set(&a), set(&b));
+            let (ints_a, ints_b) = (integers(&a), integers(&b));
+
+            prop_assert_eq!(integers(&a.union(&b)), &ints_a | &ints_b);
+            prop_assert_eq!(integers(&a.intersection(&b)), &ints_a & &ints_b);
+            prop_assert_eq!(integers(&a.difference(&b)), &ints_a - &ints_b);
+
+            let (below, above) = a.split_at(at);
+            prop_assert!(integers(&below).iter().all(|&value| value < at));
+            prop_assert!(integers(&above).iter().all(|&value| value >= at));
+            prop_assert_eq!(below.union(&above), a.clone());
+
+            prop_assert_eq!(
+                integers(&a.translate(offset)),
+                ints_a.iter().map(|value| value + offset).collect::<BTreeSet<_>>()
+            );
+            for value in -60..80 {
+                prop_assert_eq!(a.contains(value), ints_a.contains(&value));
+            }
+        }
+
+        #[test]
+        fn stays_normalized(a in intervals()) {
+            let set = set(&a);
+            let stored = set.iter().collect::<Vec<_>>();
+
+            prop_assert!(stored.iter().all(|interval| !interval.is_empty()));
+            prop_assert!(stored.windows(2).all(|pair| pair[0].end < pair[1].start));
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::gcd::extended_gcd;
+
+/// Multiplicative inverse of `a` modulo `modulus`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs.
+///
+/// Moduli need not be coprime. Returns the smallest non-negative solution together with
+/// the least common multiple of the moduli, or `None` if the congruences contradict each
+/// other or the result does not fit into `i128`.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences.iter().try_fold(
+        (0, 1),
+        |(residue, modulus), &(other_residue, other_modulus)| {
+            assert!(other_modulus > 0, "modulus must be positive");
+
+            let (g, x, _) = extended_gcd(modulus, other_modulus);
+            let difference = other_residue.checked_sub(residue)?;
+            if difference % g != 0 {
+                return None;
+            }
+
+            // residue + modulus * k ≡ other_residue (mod other_modulus)
+            let step = other_modulus / g;
+            let k = ((difference / g) % step)
+                .checked_mul(x % step)?
+                .rem_euclid(step);
+            let lcm = modulus.checked_mul(step)?;
+
+            let solution = residue.checked_add(modulus.checked_mul(k)?)?;
+
+            Some((solution.rem_euclid(lcm), lcm))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn known_systems() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 10), None);
+    }
+
+    proptest! {
+        #[test]
+        fn crt_matches_brute_force(
+            congruences in prop::collection::vec((0i128..40, 1i128..12), 1..4)
+        ) {
+            let congruences = congruences
+                .into_iter()
+                .map(|(residue, modulus)| (residue % modulus, modulus))
+                .collect::<Vec<_>>();
+            let modulus = congruences.iter().fold(1, |acc, &(_, m)| {
+                crate::lcm(acc as u128, m as u128).unwrap() as i128
+            });
+            let brute_force = (0..modulus)
+                .find(|x| congruences.iter().all(|&(r, m)| x % m == r))
+                .map(|x| (x, modulus));
+
+            prop_assert_eq!(crt(&congruences), brute_force);
+        }
+
+        #[test]
+        fn inverse_multiplies_to_one(a in -1000i128..1000, modulus in 2i128..1000) {
+            match mod_inverse(a, modulus) {
+                Some(inverse) => prop_assert_eq!((a * inverse).rem_euclid(modulus), 1),
+                None => prop_assert!((1..modulus).all(|x| (a * x).rem_euclid(modulus) != 1)),
+            }
+        }
+    }
+}
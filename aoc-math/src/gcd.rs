@@ -0,0 +1,127 @@
+/// Greatest common divisor, with `gcd(0, 0) == 0`.
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, or `None` if it does not fit into `u128`.
+pub fn lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Greatest common divisor of all `numbers`, `0` if there are none.
+pub fn gcd_all(numbers: &[u64]) -> u64 {
+    let gcd = numbers
+        .iter()
+        .fold(0, |acc, &number| gcd(acc, number as u128));
+
+    // The divisor of `u64`s is never larger than any of them.
+    gcd as u64
+}
+
+/// Least common multiple of all `numbers`, `1` if there are none, or `None` if it does not
+/// fit into `u128`.
+pub fn lcm_all(numbers: &[u64]) -> Option<u128> {
+    numbers
+        .iter()
+        .try_fold(1, |acc, &number| lcm(acc, number as u128))
+}
+
+/// Returns `(g, x, y)` such that `g` is the non-negative greatest common divisor of `a` and `b`
+/// and `a * x + b * y == g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn brute_force_gcd(a: u128, b: u128) -> u128 {
+        (1..=a.max(b))
+            .rev()
+            .find(|&d| a.is_multiple_of(d) && b.is_multiple_of(d))
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(u128::MAX, 2), None);
+        assert_eq!(gcd_all(&[]), 0);
+        assert_eq!(lcm_all(&[]), Some(1));
+        assert_eq!(lcm_all(&[2, 3, 4]), Some(12));
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+    }
+
+    #[test]
+    fn lcm_larger_than_u64() {
+        let primes = [4_294_967_291, 4_294_967_279, 4_294_967_231];
+
+        assert_eq!(
+            lcm_all(&primes),
+            Some(4_294_967_291 * 4_294_967_279 * 4_294_967_231)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_matches_brute_force(a in 0u128..500, b in 0u128..500) {
+            prop_assert_eq!(gcd(a, b), brute_force_gcd(a, b));
+        }
+
+        #[test]
+        fn lcm_matches_brute_force(numbers in prop::collection::vec(1u64..30, 1..5)) {
+            let brute_force = (1u128..)
+                .find(|&m| numbers.iter().all(|&n| m % n as u128 == 0))
+                .unwrap();
+
+            prop_assert_eq!(lcm_all(&numbers), Some(brute_force));
+        }
+
+        #[test]
+        fn gcd_all_divides_every_number(numbers in prop::collection::vec(0u64..10_000, 1..6)) {
+            let divisor = gcd_all(&numbers);
+            let expected = (1..=10_000)
+                .rev()
+                .find(|d| numbers.iter().all(|n| n % d == 0))
+                .unwrap_or(0);
+
+            prop_assert_eq!(divisor, expected);
+        }
+
+        #[test]
+        fn extended_gcd_satisfies_bezout(a in -10_000i128..10_000, b in -10_000i128..10_000) {
+            let (g, x, y) = extended_gcd(a, b);
+
+            prop_assert_eq!(g as u128, gcd(a.unsigned_abs(), b.unsigned_abs()));
+            prop_assert_eq!(a * x + b * y, g);
+        }
+    }
+}
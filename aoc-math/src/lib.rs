@@ -0,0 +1,9 @@
+//! Exact integer arithmetic shared by the puzzles.
+
+mod crt;
+mod gcd;
+mod sqrt;
+
+pub use crt::{crt, mod_inverse};
+pub use gcd::{extended_gcd, gcd, gcd_all, lcm, lcm_all};
+pub use sqrt::isqrt;
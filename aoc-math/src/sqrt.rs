@@ -0,0 +1,50 @@
+/// Largest integer whose square is at most `n`.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method converges from above when started above the root.
+    let mut x = 1 << ((n.ilog2() / 2) + 1);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn perfect_squares_and_extremes() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt((1 << 100) - 1), (1 << 50) - 1);
+    }
+
+    proptest! {
+        #[test]
+        fn isqrt_matches_brute_force(n in 0u128..100_000) {
+            let brute_force = (0..).take_while(|r| r * r <= n).last().unwrap();
+
+            prop_assert_eq!(isqrt(n), brute_force);
+        }
+
+        #[test]
+        fn isqrt_brackets_large_numbers(n: u128) {
+            let root = isqrt(n);
+
+            prop_assert!(root * root <= n);
+            prop_assert!((root + 1).checked_mul(root + 1).is_none_or(|square| square > n));
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Implicit directed graph with non-negative integer edge costs.
+///
+/// Nodes are generated on demand, so they may carry any state a search needs, not only
+/// a position.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// Nodes reachable from `node` in a single step, with the cost of that step.
+    fn successors(&self, node: &Self::Node) -> impl IntoIterator<Item = (Self::Node, u64)>;
+}
+
+/// Cheapest path found by a search, from a start node to a goal node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    /// Sum of costs of all steps, or the number of steps for [`bfs`](crate::bfs).
+    pub cost: u64,
+    /// Visited nodes in order, including both the start and the goal.
+    pub nodes: Vec<N>,
+}
+
+impl<N> Path<N> {
+    pub fn start(&self) -> &N {
+        self.nodes.first().expect("path is never empty")
+    }
+
+    pub fn goal(&self) -> &N {
+        self.nodes.last().expect("path is never empty")
+    }
+}
+
+impl<N: Clone + Eq + Hash> Path<N> {
+    /// Follows `came_from` links back from `goal` to the node without a predecessor.
+    pub(crate) fn reconstruct(came_from: &HashMap<N, N>, goal: N, cost: u64) -> Self {
+        let mut nodes = vec![goal];
+        while let Some(previous) = came_from.get(nodes.last().unwrap()) {
+            nodes.push(previous.clone());
+        }
+        nodes.reverse();
+
+        Self { cost, nodes }
+    }
+}
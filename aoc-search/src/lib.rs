@@ -0,0 +1,5 @@
+mod graph;
+mod search;
+
+pub use graph::{Graph, Path};
+pub use search::{astar, bfs, dijkstra, flood_fill};
@@ -0,0 +1,275 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{Graph, Path};
+
+/// Finds the path with the fewest steps from any of `starts` to a node satisfying `is_goal`,
+/// ignoring edge costs.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut steps = HashMap::new();
+    let mut came_from = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if steps.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let node_steps = steps[&node];
+        if is_goal(&node) {
+            return Some(Path::reconstruct(&came_from, node, node_steps));
+        }
+
+        for (successor, _) in graph.successors(&node) {
+            if let Entry::Vacant(entry) = steps.entry(successor.clone()) {
+                entry.insert(node_steps + 1);
+                came_from.insert(successor.clone(), node.clone());
+                queue.push_back(successor);
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the cheapest path from any of `starts` to a node satisfying `is_goal`.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    astar(graph, starts, is_goal, |_| 0)
+}
+
+/// Finds the cheapest path from any of `starts` to a node satisfying `is_goal`, exploring
+/// nodes in order of their cost plus `heuristic`.
+///
+/// `heuristic` must never overestimate the remaining cost to a goal, otherwise the returned
+/// path may not be the cheapest one.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    let mut costs = HashMap::new();
+    let mut came_from = HashMap::new();
+    let mut open = BinaryHeap::new();
+
+    for start in starts {
+        costs.insert(start.clone(), 0);
+        open.push(Open {
+            priority: heuristic(&start),
+            cost: 0,
+            node: start,
+        });
+    }
+
+    while let Some(Open { cost, node, .. }) = open.pop() {
+        // The node was reached more cheaply after this entry had been pushed.
+        if cost > costs[&node] {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return Some(Path::reconstruct(&came_from, node, cost));
+        }
+
+        for (successor, step_cost) in graph.successors(&node) {
+            let successor_cost = cost + step_cost;
+            if costs
+                .get(&successor)
+                .is_some_and(|&known_cost| known_cost <= successor_cost)
+            {
+                continue;
+            }
+
+            costs.insert(successor.clone(), successor_cost);
+            came_from.insert(successor.clone(), node.clone());
+            open.push(Open {
+                priority: successor_cost + heuristic(&successor),
+                cost: successor_cost,
+                node: successor,
+            });
+        }
+    }
+
+    None
+}
+
+/// Collects every node reachable from `starts` by repeatedly following `neighbors`.
+pub fn flood_fill<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut stack = Vec::new();
+
+    for start in starts {
+        if visited.insert(start.clone()) {
+            stack.push(start);
+        }
+    }
+
+    while let Some(node) = stack.pop() {
+        for neighbor in neighbors(&node) {
+            if visited.insert(neighbor.clone()) {
+                stack.push(neighbor);
+            }
+        }
+    }
+
+    visited
+}
+
+/// Entry of the open set, ordered so that [`BinaryHeap`] pops the lowest priority first.
+struct Open<N> {
+    priority: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Open<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N> Eq for Open<N> {}
+
+impl<N> PartialOrd for Open<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Open<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Among equally promising nodes, prefer the ones further along.
+        other
+            .priority
+            .cmp(&self.priority)
+            .then(self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Weighted graph given by adjacency lists of nodes `0..n`.
+    struct Adjacency(Vec<Vec<(usize, u64)>>);
+
+    impl Graph for Adjacency {
+        type Node = usize;
+
+        fn successors(&self, node: &usize) -> impl IntoIterator<Item = (usize, u64)> {
+            self.0[*node].iter().copied()
+        }
+    }
+
+    fn diamond() -> Adjacency {
+        // 0 -> 1 -> 3 is shorter, 0 -> 2 -> 3 is cheaper.
+        Adjacency(vec![
+            vec![(1, 1), (2, 2)],
+            vec![(3, 10)],
+            vec![(4, 2)],
+            vec![],
+            vec![(3, 1)],
+        ])
+    }
+
+    /// Open grid without walls, where moving costs 1.
+    struct Open2D {
+        size: i64,
+    }
+
+    impl Graph for Open2D {
+        type Node = (i64, i64);
+
+        fn successors(&self, &(x, y): &(i64, i64)) -> impl IntoIterator<Item = ((i64, i64), u64)> {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..self.size).contains(&x) && (0..self.size).contains(&y))
+                .map(|node| (node, 1))
+        }
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let path = bfs(&diamond(), [0], |&node| node == 3).unwrap();
+
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn dijkstra_minimizes_cost() {
+        let path = dijkstra(&diamond(), [0], |&node| node == 3).unwrap();
+
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes, vec![0, 2, 4, 3]);
+        assert_eq!((*path.start(), *path.goal()), (0, 3));
+    }
+
+    #[test]
+    fn search_from_multiple_starts() {
+        let path = dijkstra(&diamond(), [0, 1], |&node| node == 3).unwrap();
+        assert_eq!(path.cost, 5);
+
+        let path = dijkstra(&diamond(), [2, 3], |&node| node == 3).unwrap();
+        assert_eq!(path.nodes, vec![3]);
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        assert_eq!(bfs(&diamond(), [3], |&node| node == 0), None);
+        assert_eq!(dijkstra(&diamond(), [4], |&node| node == 1), None);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let graph = Open2D { size: 20 };
+        let goal = (17, 11);
+        let manhattan = |&(x, y): &(i64, i64)| x.abs_diff(goal.0) + y.abs_diff(goal.1);
+
+        let astar_path = astar(&graph, [(2, 3)], |&node| node == goal, manhattan).unwrap();
+        let dijkstra_path = dijkstra(&graph, [(2, 3)], |&node| node == goal).unwrap();
+
+        assert_eq!(astar_path.cost, 23);
+        assert_eq!(dijkstra_path.cost, 23);
+        assert_eq!(astar_path.nodes.len(), 24);
+        assert!(astar_path
+            .nodes
+            .windows(2)
+            .all(|step| manhattan(&step[0]).abs_diff(manhattan(&step[1])) == 1));
+    }
+
+    #[test]
+    fn flood_fill_region() {
+        let walls = [(1, 0), (1, 1), (0, 2), (1, 2)];
+        let region = flood_fill([(0, 0)], |&(x, y): &(i64, i64)| {
+            Open2D { size: 3 }
+                .successors(&(x, y))
+                .into_iter()
+                .map(|(node, _)| node)
+                .filter(|node| !walls.contains(node))
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(region, HashSet::from([(0, 0), (0, 1)]));
+    }
+}
@@ -0,0 +1,121 @@
+use std::{io::Write, thread, time::Duration};
+
+use crate::{Frame, Palette};
+
+/// Sequence of frames showing how a solution progresses.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    palette: Palette,
+}
+
+impl Animation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the palette the frames were drawn with, used when they are exported as images.
+    pub fn with_palette(self, palette: Palette) -> Self {
+        Self { palette, ..self }
+    }
+
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn last(&self) -> Option<&Frame> {
+        self.frames.last()
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Draws the frames one after another in place, waiting `delay` between them.
+    ///
+    /// A caption with the number of the frame is drawn below each of them.
+    pub fn play(&self, out: &mut impl Write, delay: Duration) -> std::io::Result<()> {
+        // Clear the screen and hide the cursor while playing.
+        write!(out, "\x1b[2J\x1b[?25l")?;
+
+        let result = self
+            .frames
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, frame)| {
+                writeln!(
+                    out,
+                    "\x1b[H{}\x1b[0mframe {}/{}\x1b[K",
+                    frame.render_ansi(),
+                    index + 1,
+                    self.len()
+                )?;
+                out.flush()?;
+
+                if index + 1 < self.len() {
+                    thread::sleep(delay);
+                }
+                Ok(())
+            });
+
+        write!(out, "\x1b[?25h")?;
+        out.flush()?;
+        result
+    }
+}
+
+impl From<Frame> for Animation {
+    fn from(frame: Frame) -> Self {
+        Self::from_iter([frame])
+    }
+}
+
+impl FromIterator<Frame> for Animation {
+    fn from_iter<T: IntoIterator<Item = Frame>>(frames: T) -> Self {
+        Self {
+            frames: frames.into_iter().collect(),
+            palette: Palette::DEFAULT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_grid::Grid;
+
+    use super::*;
+    use crate::Cell;
+
+    #[test]
+    fn play_frames_in_place() {
+        let frames = ["ab", "cd"].map(|row| {
+            let grid: Grid<char> = row.parse().unwrap();
+            Frame::new(&grid, |&char| Cell::plain(char))
+        });
+        let animation = Animation::from_iter(frames);
+
+        let mut out = Vec::new();
+        animation.play(&mut out, Duration::ZERO).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert_eq!(
+            out,
+            "\x1b[2J\x1b[?25l\
+             \x1b[Hab\n\x1b[0mframe 1/2\x1b[K\n\
+             \x1b[Hcd\n\x1b[0mframe 2/2\x1b[K\n\
+             \x1b[?25h"
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{Cell, Color, Frame, Palette};
+
+/// Side of a tile in exported PNG images, in pixels.
+const TILE_PIXELS: usize = 8;
+
+/// File format of an exported image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Tiles as squares filled with their colors.
+    Png,
+    /// Tiles as squares with their characters drawn on top.
+    Svg,
+}
+
+impl ImageFormat {
+    /// Guesses the format from the extension of `path`.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?;
+
+        if extension.eq_ignore_ascii_case("png") {
+            Some(ImageFormat::Png)
+        } else if extension.eq_ignore_ascii_case("svg") {
+            Some(ImageFormat::Svg)
+        } else {
+            None
+        }
+    }
+}
+
+impl Frame {
+    /// Saves the frame as an image, in the format given by the extension of `path`.
+    pub fn export(&self, palette: &Palette, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is neither a .png nor an .svg file", path.display()),
+            )
+        })?;
+
+        let mut out = BufWriter::new(File::create(path)?);
+        match format {
+            ImageFormat::Png => self.write_png(palette, &mut out)?,
+            ImageFormat::Svg => self.write_svg(palette, &mut out)?,
+        }
+
+        out.flush()
+    }
+
+    /// Encodes the frame as a PNG image with every tile filled in its background color, or
+    /// its foreground color if it has no background.
+    pub fn write_png(&self, palette: &Palette, out: impl Write) -> io::Result<()> {
+        let cells = self.cells();
+        let (width, height) = (cells.width * TILE_PIXELS, cells.height * TILE_PIXELS);
+
+        let mut data = Vec::with_capacity(width * height * 3);
+        for row in cells.rows() {
+            let pixels = row
+                .iter()
+                .flat_map(|cell| {
+                    let Color { r, g, b } = fill(cell, palette);
+                    [[r, g, b]; TILE_PIXELS]
+                })
+                .flatten()
+                .collect::<Vec<_>>();
+
+            for _ in 0..TILE_PIXELS {
+                data.extend_from_slice(&pixels);
+            }
+        }
+
+        let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&data).map_err(io::Error::other)?;
+        writer.finish().map_err(io::Error::other)
+    }
+
+    /// Writes the frame as an SVG image, one unit per tile, with the characters of the tiles
+    /// drawn over their background.
+    pub fn write_svg(&self, palette: &Palette, mut out: impl Write) -> io::Result<()> {
+        let cells = self.cells();
+        let (width, height) = (cells.width, cells.height);
+
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{}" height="{}" font-family="monospace" font-size="0.9" text-anchor="middle">"#,
+            width * 12,
+            height * 12,
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<rect width="{width}" height="{height}" fill="{}"/>"#,
+            hex(palette.background)
+        )
+        .unwrap();
+
+        for ((x, y), cell) in cells.iter_indices().zip(cells.iter()) {
+            if let Some(bg) = cell.style.bg {
+                writeln!(
+                    svg,
+                    r#"<rect x="{x}" y="{y}" width="1" height="1" fill="{}"/>"#,
+                    hex(bg)
+                )
+                .unwrap();
+            }
+
+            if !cell.glyph.is_whitespace() {
+                let weight = if cell.style.bold {
+                    r#" font-weight="bold""#
+                } else {
+                    ""
+                };
+                writeln!(
+                    svg,
+                    r#"<text x="{x}.5" y="{y}.8" fill="{}"{weight}>{}</text>"#,
+                    hex(cell.style.fg.unwrap_or(palette.tile)),
+                    escape(cell.glyph)
+                )
+                .unwrap();
+            }
+        }
+        svg.push_str("</svg>\n");
+
+        out.write_all(svg.as_bytes())
+    }
+}
+
+/// Color a tile is filled with when there is no room to draw its character.
+fn fill(cell: &Cell, palette: &Palette) -> Color {
+    cell.style
+        .bg
+        .or(cell.style.fg)
+        .unwrap_or(palette.background)
+}
+
+fn hex(Color { r, g, b }: Color) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn escape(glyph: char) -> String {
+    match glyph {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        glyph => glyph.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_grid::{Grid, Pos};
+
+    use super::*;
+    use crate::Style;
+
+    fn frame() -> Frame {
+        let grid: Grid<char> = "#.\n.<".parse().unwrap();
+
+        Frame::new(&grid, |&char| match char {
+            '#' => Cell::new('#', Style::fg(Color::RED)),
+            char => Cell::plain(char),
+        })
+        .with(&Palette::DEFAULT.visited([Pos::new(1, 1)]))
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_eq!(ImageFormat::from_path("out.png"), Some(ImageFormat::Png));
+        assert_eq!(
+            ImageFormat::from_path("dir/OUT.SVG"),
+            Some(ImageFormat::Svg)
+        );
+        assert_eq!(ImageFormat::from_path("out.txt"), None);
+        assert_eq!(ImageFormat::from_path("png"), None);
+    }
+
+    #[test]
+    fn encode_png() {
+        let mut png = Vec::new();
+        frame().write_png(&Palette::DEFAULT, &mut png).unwrap();
+
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data).unwrap();
+
+        assert_eq!((info.width, info.height), (16, 16));
+        let pixel = |x: usize, y: usize| {
+            let offset = (y * 16 + x) * 3;
+            Color::rgb(data[offset], data[offset + 1], data[offset + 2])
+        };
+        assert_eq!(pixel(0, 0), Color::RED);
+        assert_eq!(pixel(7, 7), Color::RED);
+        assert_eq!(pixel(8, 0), Palette::DEFAULT.background);
+        assert_eq!(pixel(15, 15), Palette::DEFAULT.visited);
+    }
+
+    #[test]
+    fn write_svg() {
+        let mut svg = Vec::new();
+        frame().write_svg(&Palette::DEFAULT, &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains(r##"<text x="0.5" y="0.8" fill="#e6463c">#</text>"##));
+        assert!(svg.contains(r##"<rect x="1" y="1" width="1" height="1" fill="#3c3214"/>"##));
+        assert!(svg.contains("&lt;</text>"));
+    }
+}
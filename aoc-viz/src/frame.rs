@@ -0,0 +1,142 @@
+use std::fmt::Display;
+
+use aoc_grid::Grid;
+
+use crate::{Overlay, Style};
+
+/// Character drawn for a single tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, style: Style) -> Self {
+        Self { glyph, style }
+    }
+
+    pub const fn plain(glyph: char) -> Self {
+        Self::new(glyph, Style::PLAIN)
+    }
+}
+
+impl From<Cell> for char {
+    fn from(cell: Cell) -> Self {
+        cell.glyph
+    }
+}
+
+/// Picture of a grid with overlays drawn on top of it.
+///
+/// Displays as plain characters; [`Frame::render_ansi`] includes the colors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    /// Draws every tile of `grid` as the cell returned by `draw`.
+    pub fn new<T>(grid: &Grid<T>, draw: impl FnMut(&T) -> Cell) -> Self {
+        Self {
+            cells: grid.map(draw),
+        }
+    }
+
+    /// Draws `overlay` on top of the frame, ignoring its tiles outside of the grid.
+    pub fn overlay(&mut self, overlay: &Overlay) {
+        for (pos, glyph) in overlay.tiles() {
+            if let Some(cell) = self.cells.get_mut(pos.x, pos.y) {
+                cell.glyph = glyph.unwrap_or(cell.glyph);
+                cell.style = overlay.style().over(cell.style);
+            }
+        }
+    }
+
+    /// Returns the frame with `overlay` drawn on top of it.
+    pub fn with(mut self, overlay: &Overlay) -> Self {
+        self.overlay(overlay);
+        self
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Draws the frame with ANSI escape sequences for colors, one line per row.
+    pub fn render_ansi(&self) -> String {
+        let mut output = String::new();
+        for row in self.cells.rows() {
+            let mut style = Style::PLAIN;
+            for cell in row {
+                if cell.style != style {
+                    style = cell.style;
+                    output.push_str(&style.ansi());
+                }
+                output.push(cell.glyph);
+            }
+            if style != Style::PLAIN {
+                output.push_str(&Style::PLAIN.ansi());
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.cells.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_grid::Pos;
+
+    use super::*;
+    use crate::Color;
+
+    fn frame() -> Frame {
+        let grid: Grid<char> = "..#\n...".parse().unwrap();
+
+        Frame::new(&grid, |&char| match char {
+            '#' => Cell::new('#', Style::fg(Color::GRAY)),
+            char => Cell::plain(char),
+        })
+    }
+
+    #[test]
+    fn draw_overlays_in_order() {
+        let frame = frame()
+            .with(&Overlay::visited([
+                Pos::new(0, 0),
+                Pos::new(1, 0),
+                Pos::new(2, 0),
+            ]))
+            .with(&Overlay::path([
+                Pos::new(0, 0),
+                Pos::new(0, 1),
+                Pos::new(1, 1),
+            ]))
+            .with(&Overlay::highlight([Pos::new(5, 5)]));
+
+        assert_eq!(frame.to_string(), "v.#\n>..\n");
+        assert_eq!(
+            frame.cells()[Pos::new(0, 0)].style,
+            Style::fg(Color::ORANGE)
+                .bold()
+                .over(Style::bg(Color::rgb(60, 50, 20)))
+        );
+        assert_eq!(frame.cells()[Pos::new(2, 1)].style, Style::PLAIN);
+    }
+
+    #[test]
+    fn render_changes_of_style() {
+        let gray = Style::fg(Color::GRAY).ansi();
+        let plain = Style::PLAIN.ansi();
+
+        assert_eq!(frame().render_ansi(), format!("..{gray}#{plain}\n...\n"));
+    }
+}
@@ -0,0 +1,19 @@
+//! Drawing grids with overlays to the terminal and to image files.
+//!
+//! A [`Frame`] starts from the tiles of a [`Grid`](aoc_grid::Grid), and [`Overlay`]s such as
+//! a path or a set of visited tiles are drawn on top of it in order. Frames collected into
+//! an [`Animation`] can be played back step by step, or exported as PNG or SVG images.
+
+mod animation;
+mod export;
+mod frame;
+mod overlay;
+mod palette;
+mod style;
+
+pub use animation::Animation;
+pub use export::ImageFormat;
+pub use frame::{Cell, Frame};
+pub use overlay::{arrow, Overlay};
+pub use palette::Palette;
+pub use style::{Color, Style};
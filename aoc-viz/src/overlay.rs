@@ -0,0 +1,129 @@
+use aoc_grid::{Direction, Pos};
+
+use crate::{Palette, Style};
+
+/// Layer of tiles drawn over a frame in a common style.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overlay {
+    /// Tiles of the layer, each optionally replacing the character drawn there.
+    tiles: Vec<(Pos, Option<char>)>,
+    style: Style,
+}
+
+impl Overlay {
+    /// Restyles `tiles`, keeping their characters.
+    pub fn new(tiles: impl IntoIterator<Item = Pos>, style: Style) -> Self {
+        Self {
+            tiles: tiles.into_iter().map(|pos| (pos, None)).collect(),
+            style,
+        }
+    }
+
+    /// Tiles visited by a search or simulation, in the colors of [`Palette::DEFAULT`].
+    pub fn visited(tiles: impl IntoIterator<Item = Pos>) -> Self {
+        Palette::DEFAULT.visited(tiles)
+    }
+
+    /// Tiles of particular interest, in the colors of [`Palette::DEFAULT`].
+    pub fn highlight(tiles: impl IntoIterator<Item = Pos>) -> Self {
+        Palette::DEFAULT.highlight(tiles)
+    }
+
+    /// Path through consecutive neighboring tiles, drawn as arrows in the direction of travel
+    /// in the colors of [`Palette::DEFAULT`].
+    ///
+    /// The last tile keeps its character, since there is no step leaving it.
+    pub fn path(tiles: impl IntoIterator<Item = Pos>) -> Self {
+        Palette::DEFAULT.path(tiles)
+    }
+
+    pub(crate) fn arrows(tiles: impl IntoIterator<Item = Pos>, style: Style) -> Self {
+        let tiles = tiles.into_iter().collect::<Vec<_>>();
+        let arrows = tiles
+            .windows(2)
+            .map(|step| {
+                let direction = Direction::all()
+                    .find(|&direction| step[0].checked_add(direction.offset()) == Some(step[1]));
+
+                (step[0], direction.map(arrow))
+            })
+            .chain(tiles.last().map(|&pos| (pos, None)));
+
+        Self {
+            tiles: arrows.collect(),
+            style,
+        }
+    }
+
+    /// Replaces the style of the layer.
+    pub fn with_style(self, style: Style) -> Self {
+        Self { style, ..self }
+    }
+
+    /// Draws every tile of the layer as `glyph`.
+    pub fn with_glyph(self, glyph: char) -> Self {
+        Self {
+            tiles: self
+                .tiles
+                .into_iter()
+                .map(|(pos, _)| (pos, Some(glyph)))
+                .collect(),
+            ..self
+        }
+    }
+
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = (Pos, Option<char>)> + '_ {
+        self.tiles.iter().copied()
+    }
+}
+
+/// Arrow pointing in `direction`.
+pub fn arrow(direction: Direction) -> char {
+    match direction {
+        Direction::North => '^',
+        Direction::East => '>',
+        Direction::South => 'v',
+        Direction::West => '<',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_arrows() {
+        let path = Overlay::path([
+            Pos::new(0, 0),
+            Pos::new(1, 0),
+            Pos::new(1, 1),
+            Pos::new(0, 1),
+        ]);
+
+        assert_eq!(
+            path.tiles().collect::<Vec<_>>(),
+            vec![
+                (Pos::new(0, 0), Some('>')),
+                (Pos::new(1, 0), Some('v')),
+                (Pos::new(1, 1), Some('<')),
+                (Pos::new(0, 1), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn path_with_jumps() {
+        // Steps that skip tiles have no direction to draw.
+        let path = Overlay::path([Pos::new(0, 0), Pos::new(2, 0)]);
+
+        assert_eq!(
+            path.tiles().collect::<Vec<_>>(),
+            vec![(Pos::new(0, 0), None), (Pos::new(2, 0), None)]
+        );
+        assert!(Overlay::path([]).tiles().next().is_none());
+    }
+}
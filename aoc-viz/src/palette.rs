@@ -0,0 +1,51 @@
+use aoc_grid::Pos;
+
+use crate::{Color, Overlay, Style};
+
+/// Colors a day draws its tiles and overlays in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Palette {
+    /// Behind tiles without a background color of their own, in exported images.
+    pub background: Color,
+    /// Ordinary tiles.
+    pub tile: Color,
+    /// Tiles that stand out from the ordinary ones, such as walls or mirrors.
+    pub accent: Color,
+    pub visited: Color,
+    pub path: Color,
+    pub highlight: Color,
+}
+
+impl Palette {
+    pub const DEFAULT: Palette = Palette {
+        background: Color::rgb(20, 20, 28),
+        tile: Color::GRAY,
+        accent: Color::BLUE,
+        visited: Color::rgb(60, 50, 20),
+        path: Color::ORANGE,
+        highlight: Color::YELLOW,
+    };
+
+    /// Tiles visited by a search or simulation, shaded in the background.
+    pub fn visited(&self, tiles: impl IntoIterator<Item = Pos>) -> Overlay {
+        Overlay::new(tiles, Style::bg(self.visited))
+    }
+
+    /// Tiles of particular interest, such as the current positions of a simulation.
+    pub fn highlight(&self, tiles: impl IntoIterator<Item = Pos>) -> Overlay {
+        let style = Style::fg(self.background).bold();
+
+        Overlay::new(tiles, style.over(Style::bg(self.highlight)))
+    }
+
+    /// Path through consecutive neighboring tiles, see [`Overlay::path`].
+    pub fn path(&self, tiles: impl IntoIterator<Item = Pos>) -> Overlay {
+        Overlay::arrows(tiles, Style::fg(self.path).bold())
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
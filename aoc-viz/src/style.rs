@@ -0,0 +1,137 @@
+use std::fmt::Write;
+
+/// 24-bit color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(110, 110, 110);
+    pub const RED: Color = Color::rgb(230, 70, 60);
+    pub const GREEN: Color = Color::rgb(90, 200, 90);
+    pub const BLUE: Color = Color::rgb(80, 130, 230);
+    pub const YELLOW: Color = Color::rgb(240, 200, 60);
+    pub const ORANGE: Color = Color::rgb(240, 140, 40);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Blends linearly from `self` at `t = 0` to `other` at `t = 1`.
+    pub fn lerp(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Color::rgb(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+}
+
+/// Colors and attributes of a drawn character; unset colors keep the terminal default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub const PLAIN: Style = Style {
+        fg: None,
+        bg: None,
+        bold: false,
+    };
+
+    pub const fn fg(color: Color) -> Self {
+        Self {
+            fg: Some(color),
+            ..Self::PLAIN
+        }
+    }
+
+    pub const fn bg(color: Color) -> Self {
+        Self {
+            bg: Some(color),
+            ..Self::PLAIN
+        }
+    }
+
+    pub const fn bold(self) -> Self {
+        Self { bold: true, ..self }
+    }
+
+    /// Draws `self` over `below`, keeping whatever `self` leaves unset.
+    pub fn over(self, below: Style) -> Style {
+        Style {
+            fg: self.fg.or(below.fg),
+            bg: self.bg.or(below.bg),
+            bold: self.bold || below.bold,
+        }
+    }
+
+    /// ANSI escape sequence that switches the terminal to this style.
+    pub(crate) fn ansi(&self) -> String {
+        let mut sequence = String::from("\x1b[0");
+        if self.bold {
+            sequence.push_str(";1");
+        }
+        if let Some(Color { r, g, b }) = self.fg {
+            write!(sequence, ";38;2;{r};{g};{b}").unwrap();
+        }
+        if let Some(Color { r, g, b }) = self.bg {
+            write!(sequence, ";48;2;{r};{g};{b}").unwrap();
+        }
+        sequence.push('m');
+
+        sequence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layer_styles() {
+        let below = Style::bg(Color::BLUE);
+        let above = Style::fg(Color::RED).bold();
+
+        assert_eq!(
+            above.over(below),
+            Style {
+                fg: Some(Color::RED),
+                bg: Some(Color::BLUE),
+                bold: true
+            }
+        );
+        assert_eq!(Style::PLAIN.over(below), below);
+    }
+
+    #[test]
+    fn ansi_sequences() {
+        assert_eq!(Style::PLAIN.ansi(), "\x1b[0m");
+        assert_eq!(
+            Style::fg(Color::rgb(1, 2, 3)).bold().ansi(),
+            "\x1b[0;1;38;2;1;2;3m"
+        );
+        assert_eq!(Style::bg(Color::BLACK).ansi(), "\x1b[0;48;2;0;0;0m");
+    }
+
+    #[test]
+    fn blend_colors() {
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.0), Color::BLACK);
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 1.0), Color::WHITE);
+        assert_eq!(
+            Color::BLACK.lerp(Color::WHITE, 0.5),
+            Color::rgb(128, 128, 128)
+        );
+    }
+}
@@ -0,0 +1,34 @@
+//! Generates the list of solutions from the `dayN` dependencies of this crate, like the
+//! registry of the `aoc` runner.
+
+use std::{env, fs, path::Path};
+
+fn day_number(name: &str) -> Option<u8> {
+    name.strip_prefix("day")?.parse().ok()
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_path = Path::new(&manifest_dir).join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+
+    let manifest: toml::Table = fs::read_to_string(&manifest_path)
+        .expect("Failed to read manifest")
+        .parse()
+        .expect("Failed to parse manifest");
+
+    let mut days = manifest["dependencies"]
+        .as_table()
+        .expect("Manifest has no dependencies")
+        .keys()
+        .filter_map(|name| day_number(name))
+        .collect::<Vec<_>>();
+    days.sort_unstable();
+
+    let entries: String = days
+        .iter()
+        .map(|number| format!("    {number} => day{number}::Day{number},\n"))
+        .collect();
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out_path, format!("days! {{\n{entries}}}\n")).expect("Failed to write days");
+}
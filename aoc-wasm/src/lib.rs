@@ -0,0 +1,105 @@
+//! WebAssembly build of the solutions, callable from JavaScript through [`solve`].
+//!
+//! Day crates are built without their default features, so nothing here reads from disk,
+//! prints or spawns threads.
+
+use aoc_core::{input, Solution};
+use wasm_bindgen::prelude::*;
+
+/// Parses the input and solves the part with the given number on it.
+type Solve = fn(&str, u8) -> Result<String, String>;
+
+fn solve_day<S: Solution>(input: &str, part: u8) -> Result<String, String> {
+    let parsed = S::parse(input).map_err(|error| error.to_string())?;
+    let answer = if part == 1 {
+        S::part_1(&parsed)
+    } else {
+        S::part_2(&parsed)
+    };
+
+    answer
+        .map(|answer| answer.to_string())
+        .map_err(|error| error.in_input(input).to_string())
+}
+
+macro_rules! days {
+    ($($number:literal => $solution:ty),* $(,)?) => {
+        static DAYS: &[(u8, Solve)] = &[$(($number, solve_day::<$solution>)),*];
+    };
+}
+
+// Generated by `build.rs` from the `dayN` dependencies of this crate.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Numbers of the days that can be solved, in order.
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    DAYS.iter().map(|&(number, _)| number).collect()
+}
+
+/// Solves `part` of `day` for the puzzle `input`.
+///
+/// Errors, thrown as strings in JavaScript, describe an unknown day or part, where the input
+/// could not be parsed, or why it has no answer. A solution panicking on input it cannot
+/// handle aborts the WebAssembly instance instead.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    let &(_, solve) = DAYS
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .ok_or_else(|| format!("day {day} is not implemented"))?;
+    if !matches!(part, 1 | 2) {
+        return Err("part must be either 1 or 2".to_string());
+    }
+
+    solve(&input::normalize(input), part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Time:      7  15   30\r\nDistance:  9  40  200\r\n";
+
+    #[test]
+    fn list_days() {
+        let days = days();
+
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{days:?}");
+        assert!([6, 13].iter().all(|day| days.contains(day)), "{days:?}");
+    }
+
+    #[test]
+    fn solve_both_parts() {
+        assert_eq!(solve(6, 1, INPUT), Ok("288".to_string()));
+        assert_eq!(solve(6, 2, INPUT), Ok("71503".to_string()));
+    }
+
+    #[test]
+    fn reject_unknown_day_or_part() {
+        assert_eq!(
+            solve(26, 1, INPUT),
+            Err("day 26 is not implemented".to_string())
+        );
+        assert_eq!(
+            solve(6, 3, INPUT),
+            Err("part must be either 1 or 2".to_string())
+        );
+    }
+
+    #[test]
+    fn report_parse_error() {
+        let error = solve(6, 1, "Time: 7\nDistance: x").unwrap_err();
+
+        assert!(error.starts_with("expected"), "{error}");
+        assert!(error.contains("line 2"), "{error}");
+    }
+
+    #[test]
+    fn report_solve_error() {
+        assert_eq!(
+            solve(13, 1, "#.\n.."),
+            Err("found no line of reflection in pattern 1".to_string())
+        );
+    }
+}
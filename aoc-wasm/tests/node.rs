@@ -0,0 +1,58 @@
+//! Solves through the WebAssembly build under Node, run with
+//! `cargo test -p aoc-wasm --target wasm32-unknown-unknown`.
+
+#![cfg(target_arch = "wasm32")]
+
+use aoc_wasm::{days, solve};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn list_days() {
+    let days = days();
+
+    assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{days:?}");
+    assert!([6, 11, 12].iter().all(|day| days.contains(day)), "{days:?}");
+}
+
+#[wasm_bindgen_test]
+fn solve_example() {
+    let input = include_str!("../../day6/example.txt");
+
+    assert_eq!(solve(6, 1, input), Ok("288".to_string()));
+    assert_eq!(solve(6, 2, input), Ok("71503".to_string()));
+}
+
+#[wasm_bindgen_test]
+fn solve_without_threads() {
+    let input = include_str!("../../day12/example.txt");
+
+    assert_eq!(solve(12, 1, input), Ok("21".to_string()));
+    assert_eq!(solve(12, 2, input), Ok("525152".to_string()));
+}
+
+#[wasm_bindgen_test]
+fn solve_beyond_32_bits() {
+    // Thirty galaxies on the diagonal, each pair separated by empty rows and columns.
+    let input = (0..59)
+        .map(|y| {
+            (0..59)
+                .map(|x| if x == y && y % 2 == 0 { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert_eq!(solve(11, 1, &input), Ok("26970".to_string()));
+    assert_eq!(solve(11, 2, &input), Ok("8990008990".to_string()));
+}
+
+#[wasm_bindgen_test]
+fn report_errors() {
+    assert_eq!(
+        solve(26, 1, ""),
+        Err("day 26 is not implemented".to_string())
+    );
+
+    let error = solve(6, 1, "Time: 7\nDistance: x").unwrap_err();
+    assert!(error.contains("line 2"), "{error}");
+}
@@ -0,0 +1,129 @@
+use std::{fs, hint::black_box, path::Path, time::Duration};
+
+use aoc::{
+    bench::{self, InputKind, Phase, Record},
+    registry::{self, Part, Visitor},
+};
+use aoc_core::{input, Solution};
+use criterion::{BenchmarkId, Criterion};
+use serde::Deserialize;
+
+struct Benches<'a> {
+    criterion: &'a mut Criterion,
+}
+
+impl Visitor for Benches<'_> {
+    fn visit<S: Solution>(&mut self, number: u8) {
+        let mut group = self.criterion.benchmark_group(format!("day{number}"));
+
+        for kind in InputKind::ALL {
+            for phase in Phase::ALL {
+                let part = match phase {
+                    Phase::Parse => Part::One,
+                    Phase::Solve(part) => part,
+                };
+                if kind == InputKind::Real && registry::is_slow(number, part) {
+                    continue;
+                }
+
+                let path = match kind {
+                    InputKind::Example => aoc::example_path(number, part),
+                    InputKind::Real => aoc::input_path(number),
+                };
+                // A day generated from the template has no input and nothing to solve yet.
+                let contents = match input::read(&path) {
+                    Ok(contents) if !contents.trim().is_empty() => contents,
+                    _ => {
+                        eprintln!("Skipping day {number} {phase} on {}", path.display());
+                        continue;
+                    }
+                };
+                let id = BenchmarkId::new(phase.id(), kind.id());
+
+                if phase == Phase::Parse {
+                    group.bench_function(id, |b| b.iter(|| S::parse(black_box(&contents))));
+                    continue;
+                }
+
+                let input = match S::parse(&contents) {
+                    Ok(input) => input,
+                    Err(err) => {
+                        eprintln!("Skipping day {number} {phase} on {}: {err}", path.display());
+                        continue;
+                    }
+                };
+                group.bench_function(id, |b| match part {
+                    Part::One => b.iter(|| S::part_1(black_box(&input))),
+                    Part::Two => b.iter(|| S::part_2(black_box(&input))),
+                });
+            }
+        }
+
+        group.finish();
+    }
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    median: Estimate,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+fn read_median(path: &Path) -> Option<Duration> {
+    let contents = fs::read_to_string(path).ok()?;
+    let estimates: Estimates = serde_json::from_str(&contents).ok()?;
+
+    Some(Duration::from_nanos(estimates.median.point_estimate as u64))
+}
+
+/// Collects medians of every benchmark that has results, including ones from earlier runs.
+fn collect_records() -> Vec<Record> {
+    let criterion_dir = bench::criterion_dir();
+
+    let mut records = Vec::new();
+    for day in registry::DAYS {
+        for phase in Phase::ALL {
+            for input in InputKind::ALL {
+                let path = criterion_dir
+                    .join(format!("day{}", day.number))
+                    .join(phase.id())
+                    .join(input.id())
+                    .join("new/estimates.json");
+
+                if let Some(median) = read_median(&path) {
+                    records.push(Record {
+                        day: day.number,
+                        phase,
+                        input,
+                        median,
+                    });
+                }
+            }
+        }
+    }
+
+    records
+}
+
+fn main() {
+    let mut criterion = Criterion::default()
+        .sample_size(20)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3))
+        .configure_from_args();
+
+    registry::visit(&mut Benches {
+        criterion: &mut criterion,
+    });
+    criterion.final_summary();
+
+    let path = bench::summary_path();
+    match bench::write_summary(&path, &collect_records()) {
+        Ok(()) => println!("Summary written to {}", path.display()),
+        Err(err) => eprintln!("Failed to write summary to {}: {err}", path.display()),
+    }
+}
@@ -0,0 +1,56 @@
+//! Generates the lists of registered solutions and input generators from the `dayN`
+//! dependencies of this crate.
+
+use std::{env, fs, path::Path};
+
+fn day_number(name: &str) -> Option<u8> {
+    name.strip_prefix("day")?.parse().ok()
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_path = Path::new(&manifest_dir).join("Cargo.toml");
+    let workspace_dir = Path::new(&manifest_dir).join("..");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+    println!("cargo:rerun-if-changed={}", workspace_dir.display());
+
+    let manifest: toml::Table = fs::read_to_string(&manifest_path)
+        .expect("Failed to read manifest")
+        .parse()
+        .expect("Failed to parse manifest");
+
+    let mut days = manifest["dependencies"]
+        .as_table()
+        .expect("Manifest has no dependencies")
+        .keys()
+        .filter_map(|name| day_number(name))
+        .collect::<Vec<_>>();
+    days.sort_unstable();
+
+    for entry in fs::read_dir(&workspace_dir).expect("Failed to read workspace") {
+        let name = entry.unwrap().file_name();
+        if let Some(number) = name.to_str().and_then(day_number) {
+            if !days.contains(&number) {
+                println!(
+                    "cargo:warning=day{number} is not registered, add it with `cargo add -p aoc --path day{number}`"
+                );
+            }
+        }
+    }
+
+    let entries: String = days
+        .iter()
+        .map(|number| format!("    {number} => day{number}::Day{number},\n"))
+        .collect();
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, format!("solutions! {{\n{entries}}}\n"))
+        .expect("Failed to write solutions");
+
+    let entries: String = days
+        .iter()
+        .map(|number| format!("    {number} => day{number},\n"))
+        .collect();
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("generators.rs");
+    fs::write(out_path, format!("generators! {{\n{entries}}}\n"))
+        .expect("Failed to write generators");
+}
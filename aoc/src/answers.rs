@@ -0,0 +1,125 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::registry::Part;
+
+#[derive(Debug, Default, Deserialize)]
+struct DayAnswers {
+    part_1: Option<toml::Value>,
+    part_2: Option<toml::Value>,
+}
+
+/// Known-good answers to puzzle inputs, as recorded in `answers.toml`.
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+impl Answers {
+    /// Path of the `answers.toml` file at the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../answers.toml")
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LoadAnswersError> {
+        let contents = fs::read_to_string(path).map_err(LoadAnswersError::Io)?;
+
+        contents.parse()
+    }
+
+    /// Returns the recorded answer formatted the same way as [`aoc_core::Answer`] is displayed.
+    pub fn get(&self, day: u8, part: Part) -> Option<String> {
+        let answers = self.days.get(&day)?;
+        let answer = match part {
+            Part::One => answers.part_1.as_ref(),
+            Part::Two => answers.part_2.as_ref(),
+        }?;
+
+        let answer = match answer {
+            toml::Value::String(text) => text.clone(),
+            value => value.to_string(),
+        };
+
+        Some(answer)
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = LoadAnswersError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let tables: BTreeMap<String, DayAnswers> =
+            toml::from_str(input).map_err(LoadAnswersError::Toml)?;
+
+        let days = tables
+            .into_iter()
+            .map(|(key, answers)| {
+                key.strip_prefix("day")
+                    .and_then(|number| number.parse().ok())
+                    .map(|number| (number, answers))
+                    .ok_or(LoadAnswersError::InvalidDay(key))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { days })
+    }
+}
+
+#[derive(Debug)]
+pub enum LoadAnswersError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    InvalidDay(String),
+}
+
+impl Display for LoadAnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadAnswersError::Io(err) => write!(f, "{err}"),
+            LoadAnswersError::Toml(err) => write!(f, "{err}"),
+            LoadAnswersError::InvalidDay(key) => {
+                write!(f, "expected table named `dayN`, found `{key}`")
+            }
+        }
+    }
+}
+
+impl Error for LoadAnswersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers: Answers = "[day1]
+part_1 = 142
+part_2 = \"abc\"
+
+[day12]
+part_2 = 525152
+"
+        .parse()
+        .unwrap();
+
+        assert_eq!(answers.get(1, Part::One).as_deref(), Some("142"));
+        assert_eq!(answers.get(1, Part::Two).as_deref(), Some("abc"));
+        assert_eq!(answers.get(12, Part::One), None);
+        assert_eq!(answers.get(12, Part::Two).as_deref(), Some("525152"));
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn reject_invalid_day() {
+        let result = "[first]\npart_1 = 1".parse::<Answers>();
+
+        assert!(matches!(result, Err(LoadAnswersError::InvalidDay(key)) if key == "first"));
+    }
+}
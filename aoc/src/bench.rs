@@ -0,0 +1,189 @@
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::registry::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [
+        Phase::Parse,
+        Phase::Solve(Part::One),
+        Phase::Solve(Part::Two),
+    ];
+
+    /// Name of the benchmark function measuring this phase.
+    pub fn id(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Solve(Part::One) => "part_1",
+            Phase::Solve(Part::Two) => "part_2",
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Solve(part) => f.pad(&format!("part {part}")),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Phase::ALL
+            .into_iter()
+            .find(|phase| phase.id() == input)
+            .ok_or("phase must be one of parse, part_1 or part_2")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+impl InputKind {
+    pub const ALL: [InputKind; 2] = [InputKind::Example, InputKind::Real];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            InputKind::Example => "example",
+            InputKind::Real => "input",
+        }
+    }
+}
+
+impl FromStr for InputKind {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        InputKind::ALL
+            .into_iter()
+            .find(|kind| kind.id() == input)
+            .ok_or("input must be either example or input")
+    }
+}
+
+/// Median time of a single benchmark.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub phase: Phase,
+    pub input: InputKind,
+    pub median: Duration,
+}
+
+/// Directory where criterion stores its results.
+pub fn criterion_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("CRITERION_HOME") {
+        return dir.into();
+    }
+
+    let target_dir = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../target"));
+
+    target_dir.join("criterion")
+}
+
+/// Path of the summary table written by the `days` benchmark.
+pub fn summary_path() -> PathBuf {
+    criterion_dir().join("aoc-summary.csv")
+}
+
+pub fn write_summary(path: impl AsRef<Path>, records: &[Record]) -> io::Result<()> {
+    let mut contents = String::from("day,phase,input,median_ns\n");
+    for record in records {
+        contents += &format!(
+            "{},{},{},{}\n",
+            record.day,
+            record.phase.id(),
+            record.input.id(),
+            record.median.as_nanos()
+        );
+    }
+
+    fs::write(path, contents)
+}
+
+pub fn read_summary(path: impl AsRef<Path>) -> io::Result<Vec<Record>> {
+    let contents = fs::read_to_string(path)?;
+
+    contents
+        .lines()
+        .skip(1)
+        .map(|line| {
+            parse_record(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid record {line:?}"),
+                )
+            })
+        })
+        .collect()
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    let mut fields = line.split(',');
+    let record = Record {
+        day: fields.next()?.parse().ok()?,
+        phase: fields.next()?.parse().ok()?,
+        input: fields.next()?.parse().ok()?,
+        median: Duration::from_nanos(fields.next()?.parse().ok()?),
+    };
+
+    fields.next().is_none().then_some(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_round_trip() {
+        let records = vec![
+            Record {
+                day: 1,
+                phase: Phase::Parse,
+                input: InputKind::Example,
+                median: Duration::from_nanos(1250),
+            },
+            Record {
+                day: 17,
+                phase: Phase::Solve(Part::Two),
+                input: InputKind::Real,
+                median: Duration::from_millis(42),
+            },
+        ];
+        let path = env::temp_dir().join(format!("aoc-summary-{}.csv", std::process::id()));
+
+        write_summary(&path, &records).unwrap();
+        let read = read_summary(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(read, records);
+    }
+
+    #[test]
+    fn reject_malformed_record() {
+        assert_eq!(parse_record("1,parse,example"), None);
+        assert_eq!(parse_record("1,solve,example,10"), None);
+        assert_eq!(parse_record("1,parse,example,10,20"), None);
+    }
+}
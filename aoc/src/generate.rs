@@ -0,0 +1,33 @@
+use aoc_gen::GenRng;
+
+/// Generates a random puzzle input of the given size from a random number generator.
+pub type Generate = fn(usize, &mut GenRng) -> String;
+
+macro_rules! generators {
+    ($($number:literal => $day:ident),* $(,)?) => {
+        /// Input generators of each day, with the size of their real puzzle inputs.
+        pub static GENERATORS: &[(u8, Generate, usize)] =
+            &[$(($number, $day::generate, $day::INPUT_SIZE)),*];
+    };
+}
+
+// Generated by `build.rs` from the `dayN` dependencies of this crate.
+include!(concat!(env!("OUT_DIR"), "/generators.rs"));
+
+/// Generator of `day` with its default size.
+pub fn find(day: u8) -> Option<(Generate, usize)> {
+    GENERATORS
+        .iter()
+        .find(|&&(number, _, _)| number == day)
+        .map(|&(_, generate, size)| (generate, size))
+}
+
+/// Generates an input for `day` of the given size, reproducibly for a given seed.
+pub fn generate(day: u8, size: Option<usize>, seed: u64) -> Option<String> {
+    let (generate, default_size) = find(day)?;
+
+    Some(generate(
+        size.unwrap_or(default_size),
+        &mut aoc_gen::rng(seed),
+    ))
+}
@@ -0,0 +1,34 @@
+use std::path::{Path, PathBuf};
+
+use registry::Part;
+
+pub mod answers;
+pub mod bench;
+pub mod generate;
+pub mod registry;
+pub mod report;
+pub mod serve;
+pub mod stats;
+pub mod verify;
+pub mod viz;
+
+/// Path of the puzzle input for `day`, see [`aoc_core::input::input_path`].
+pub fn input_path(day: u8) -> PathBuf {
+    let day_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{day}"));
+
+    aoc_core::input::input_path(day_dir)
+}
+
+/// Path of the example input from the puzzle description for `part` of `day`.
+///
+/// Days whose second part comes with a different example keep it in `example2.txt`.
+pub fn example_path(day: u8, part: Part) -> PathBuf {
+    let day_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../day{day}"));
+    let part_2_example = day_dir.join("example2.txt");
+
+    if part == Part::Two && part_2_example.exists() {
+        part_2_example
+    } else {
+        day_dir.join("example.txt")
+    }
+}
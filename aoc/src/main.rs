@@ -0,0 +1,608 @@
+use std::{
+    collections::BTreeMap,
+    io::{self, IsTerminal, Read, Write},
+    iter, panic,
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
+    time::{Duration, Instant, SystemTime},
+};
+
+use aoc::{
+    answers::Answers,
+    bench::{self, InputKind, Phase},
+    generate,
+    registry::{self, Outcome, Part, PartError, PartOutcome},
+    report::{self, Failure, Format},
+    serve::Server,
+    stats::{Bytes, CountingAllocator},
+    verify::{self, Check, Status},
+    viz,
+};
+use aoc_core::input;
+use aoc_viz::ImageFormat;
+use clap::{ArgAction, Parser, Subcommand};
+use log::LevelFilter;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code 2023 solutions")]
+struct Cli {
+    /// Show diagnostics of the solutions, `-vv` for more detail.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day.
+    Run {
+        day: u8,
+
+        /// Solve only the given part (1 or 2).
+        #[arg(long)]
+        part: Option<Part>,
+
+        /// Path to the puzzle input, or `-` to read it from standard input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Report time and heap usage of parsing and each part.
+        #[arg(long, conflicts_with = "format")]
+        stats: bool,
+
+        /// Print results as a `table`, `json` or `csv` instead of a summary for people.
+        #[arg(long)]
+        format: Option<Format>,
+    },
+    /// Solve every day and print a summary of the results.
+    All {
+        /// Solve one day at a time, for more stable timings.
+        #[arg(long)]
+        serial: bool,
+
+        /// Also solve the parts that take minutes on the real input.
+        #[arg(long)]
+        slow: bool,
+
+        /// Print results as a `table`, `json` or `csv`.
+        #[arg(long, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Re-solve days against their inputs and compare with recorded answers.
+    Verify {
+        /// Days to verify; all of them if none are given.
+        days: Vec<u8>,
+
+        /// Path to the file with recorded answers.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Show how a day is solved, animated step by step in the terminal.
+    Viz {
+        day: u8,
+
+        /// Visualise the given part (1 or 2) instead of the first one that can be.
+        #[arg(long)]
+        part: Option<Part>,
+
+        /// Path to the puzzle input, or `-` to read it from standard input.
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Milliseconds to wait between frames.
+        #[arg(long, default_value_t = 50)]
+        delay: u64,
+
+        /// Show only the final frame.
+        #[arg(long)]
+        last: bool,
+
+        /// Save the final frame to a `.png` or `.svg` file instead of showing it.
+        #[arg(long, value_name = "PATH")]
+        export: Option<PathBuf>,
+    },
+    /// Print a random puzzle input for a day.
+    Gen {
+        day: u8,
+
+        /// Scale of the input, such as the number of lines or the side of the grid; defaults
+        /// to the scale of real puzzle inputs.
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Seed of the random generator; a random one is picked and reported if not given.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Answer requests to solve puzzle inputs over HTTP on localhost.
+    Serve {
+        #[arg(long, default_value_t = 2023)]
+        port: u16,
+    },
+    /// Print median times measured by the `days` benchmark.
+    Bench {
+        /// Run `cargo bench` first instead of printing results of the last run.
+        #[arg(long)]
+        run: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let level = match cli.verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .format_timestamp(None)
+        .parse_default_env()
+        .init();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            stats,
+            format,
+        } => run(day, part, input, stats, format),
+        Command::All {
+            serial,
+            slow,
+            format,
+        } => all(serial, slow, format),
+        Command::Verify { days, answers } => verify(days, answers),
+        Command::Viz {
+            day,
+            part,
+            input,
+            delay,
+            last,
+            export,
+        } => viz(day, part, input, Duration::from_millis(delay), last, export),
+        Command::Gen { day, size, seed } => gen(day, size, seed),
+        Command::Serve { port } => serve(port),
+        Command::Bench { run } => bench(run),
+    }
+}
+
+fn read_input(day: u8, path: Option<PathBuf>) -> io::Result<String> {
+    match path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut contents = String::new();
+            io::stdin().read_to_string(&mut contents)?;
+            Ok(input::normalize(&contents))
+        }
+        Some(path) => input::read(path),
+        None => input::read(aoc::input_path(day)),
+    }
+}
+
+fn run(
+    day: u8,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    stats: bool,
+    format: Option<Format>,
+) -> ExitCode {
+    let Some(solution) = registry::find(day) else {
+        eprintln!("Day {day} is not implemented");
+        return ExitCode::FAILURE;
+    };
+
+    let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+
+    if let Some(format) = format {
+        let records = report::solve(solution, read_input(day, input), &parts);
+        return write_report(format, &records);
+    }
+
+    let contents = match read_input(day, input) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("Failed to read puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let outcome = match panic::catch_unwind(|| solution.solve(&contents, &parts)) {
+        Ok(Ok(outcome)) => outcome,
+        Ok(Err(err)) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+        Err(payload) => {
+            eprintln!(
+                "Parsing the puzzle input panicked: {}",
+                registry::panic_message(&*payload)
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Parsed input ({:.2?})", outcome.parse_time);
+
+    let mut exit_code = ExitCode::SUCCESS;
+    for PartOutcome {
+        part,
+        answer,
+        elapsed,
+        ..
+    } in &outcome.parts
+    {
+        match answer {
+            Ok(answer) if answer.is_multiline() => {
+                println!("Part {part}: ({elapsed:.2?})\n{answer}")
+            }
+            Ok(answer) => println!("Part {part}: {answer} ({elapsed:.2?})"),
+            Err(PartError::Solve(err)) => {
+                eprintln!(
+                    "Part {part}: failed after {elapsed:.2?}: {}",
+                    err.in_input(&contents)
+                );
+                exit_code = ExitCode::FAILURE;
+            }
+            Err(PartError::Panic(message)) => {
+                eprintln!("Part {part}: panicked after {elapsed:.2?}: {message}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if stats {
+        print_stats(&outcome);
+    }
+
+    exit_code
+}
+
+/// Prints `records` in `format`, failing if any part has no answer.
+fn write_report(format: Format, records: &[report::Record]) -> ExitCode {
+    match report::write(format, records, io::stdout().lock()) {
+        Ok(()) => (),
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => (),
+        Err(err) => {
+            eprintln!("Failed to write the results: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    // Skipped parts were left out on purpose.
+    let failed = records
+        .iter()
+        .any(|record| !matches!(record.error, None | Some(Failure::Skipped { .. })));
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_stats(outcome: &Outcome) {
+    let phases = iter::once((Phase::Parse, outcome.parse_time, outcome.parse_allocations)).chain(
+        outcome
+            .parts
+            .iter()
+            .map(|part| (Phase::Solve(part.part), part.elapsed, part.allocations)),
+    );
+
+    println!();
+    println!(
+        "{:<6}  {:>10}  {:>10}  {:>12}  {:>12}",
+        "Phase", "Time", "Allocs", "Allocated", "Peak"
+    );
+    for (phase, elapsed, allocations) in phases {
+        println!(
+            "{:<6}  {:>10}  {:>10}  {:>12}  {:>12}",
+            phase,
+            format!("{elapsed:.2?}"),
+            allocations.count,
+            Bytes(allocations.total_bytes),
+            Bytes(allocations.peak_bytes)
+        );
+    }
+}
+
+fn all(serial: bool, slow: bool, format: Format) -> ExitCode {
+    let start = Instant::now();
+    let records = report::solve_all(registry::DAYS, serial, slow, |day| {
+        input::read(aoc::input_path(day))
+    });
+    let elapsed = start.elapsed();
+
+    let exit_code = write_report(format, &records);
+
+    let solved = records
+        .iter()
+        .filter(|record| record.answer.is_some())
+        .count();
+    let skipped = records
+        .iter()
+        .filter(|record| matches!(record.error, Some(Failure::Skipped { .. })))
+        .count();
+    let mut summary = format!(
+        "Solved {solved} of {} parts in {elapsed:.2?}",
+        records.len()
+    );
+    if skipped > 0 {
+        summary += &format!(" ({skipped} skipped as slow, pass --slow to solve)");
+    }
+    // Keep machine-readable output free of anything else.
+    if format == Format::Table {
+        println!("\n{summary}");
+    } else {
+        eprintln!("{summary}");
+    }
+
+    exit_code
+}
+
+fn verify(days: Vec<u8>, answers: Option<PathBuf>) -> ExitCode {
+    let answers_path = answers.unwrap_or_else(Answers::default_path);
+    let answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Failed to load {}: {err}", answers_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let days = if days.is_empty() {
+        registry::DAYS.iter().collect()
+    } else {
+        let mut found = Vec::with_capacity(days.len());
+        for day in days {
+            match registry::find(day) {
+                Some(day) => found.push(day),
+                None => {
+                    eprintln!("Day {day} is not implemented");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        found
+    };
+
+    println!(
+        "{:>3}  {:>4}  {:>20}  {:>20}  {:>10}  Status",
+        "Day", "Part", "Expected", "Actual", "Time"
+    );
+
+    let mut failures = 0;
+    for day in days {
+        let checks = match input::read(aoc::input_path(day.number)) {
+            Ok(input) => verify::verify(day, &input, &answers, &Part::ALL),
+            Err(err) => Part::ALL
+                .iter()
+                .map(|&part| Check {
+                    day: day.number,
+                    part,
+                    expected: answers.get(day.number, part),
+                    actual: Err(format!("no input: {err}")),
+                    elapsed: Default::default(),
+                })
+                .collect(),
+        };
+
+        for check in checks {
+            let status = check.status();
+            if !status.is_ok() {
+                failures += 1;
+            }
+
+            let actual = match &check.actual {
+                Ok(answer) => answer.as_str(),
+                Err(reason) => reason.as_str(),
+            };
+            let status = match status {
+                Status::Correct => "ok",
+                Status::Incorrect => "MISMATCH",
+                Status::Unrecorded => "unrecorded",
+                Status::Failed => "FAILED",
+            };
+
+            println!(
+                "{:>3}  {:>4}  {:>20}  {:>20}  {:>10}  {status}",
+                check.day,
+                check.part,
+                check.expected.as_deref().unwrap_or("-"),
+                actual,
+                format!("{:.2?}", check.elapsed),
+            );
+        }
+    }
+
+    if failures > 0 {
+        eprintln!("{failures} part(s) did not match the recorded answers");
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn viz(
+    day: u8,
+    part: Option<Part>,
+    input: Option<PathBuf>,
+    delay: Duration,
+    last: bool,
+    export: Option<PathBuf>,
+) -> ExitCode {
+    if let Some(path) = &export {
+        if ImageFormat::from_path(path).is_none() {
+            eprintln!(
+                "Cannot export to {}, use a .png or .svg file",
+                path.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let parts = viz::parts(day);
+    let Some(part) = part.or(parts.first().copied()) else {
+        eprintln!("Day {day} has no visualisation");
+        return ExitCode::FAILURE;
+    };
+    let Some(visualize) = viz::find(day, part) else {
+        let parts = parts.iter().map(Part::to_string).collect::<Vec<_>>();
+        eprintln!(
+            "Day {day} part {part} has no visualisation, try part {}",
+            parts.join(" or ")
+        );
+        return ExitCode::FAILURE;
+    };
+
+    let contents = match read_input(day, input) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("Failed to read puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let animation = match visualize(&contents) {
+        Ok(animation) => animation,
+        Err(err) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let last_frame = animation.last().expect("animation has frames");
+
+    if let Some(path) = export {
+        return match last_frame.export(animation.palette(), &path) {
+            Ok(()) => {
+                println!("Saved {}", path.display());
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("Failed to export {}: {err}", path.display());
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut stdout = io::stdout().lock();
+    let result = if !stdout.is_terminal() {
+        // Escape sequences would only clutter a file or pipe.
+        write!(stdout, "{last_frame}")
+    } else if last {
+        write!(stdout, "{}", last_frame.render_ansi())
+    } else {
+        animation.play(&mut stdout, delay)
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Failed to draw the visualisation: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn gen(day: u8, size: Option<usize>, seed: Option<u64>) -> ExitCode {
+    let seed = seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+        eprintln!("Seed: {seed}");
+        seed
+    });
+
+    let Some(input) = generate::generate(day, size, seed) else {
+        eprintln!("Day {day} has no input generator");
+        return ExitCode::FAILURE;
+    };
+
+    match writeln!(io::stdout().lock(), "{input}") {
+        // Piping into `head` and the like is fine.
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Failed to write the input: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn serve(port: u16) -> ExitCode {
+    let server = match Server::bind(("127.0.0.1", port)) {
+        Ok(server) => server,
+        Err(err) => {
+            eprintln!("Failed to listen on port {port}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Listening on http://{}", server.local_addr());
+    server.run();
+
+    ExitCode::SUCCESS
+}
+
+fn bench(run: bool) -> ExitCode {
+    if run {
+        let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let status = process::Command::new(env!("CARGO"))
+            .args(["bench", "-p", "aoc", "--bench", "days"])
+            .current_dir(workspace_dir)
+            .status();
+
+        match status {
+            Ok(status) if status.success() => (),
+            Ok(status) => {
+                eprintln!("cargo bench failed: {status}");
+                return ExitCode::FAILURE;
+            }
+            Err(err) => {
+                eprintln!("Failed to run cargo bench: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let path = bench::summary_path();
+    let records = match bench::read_summary(&path) {
+        Ok(records) => records,
+        Err(err) => {
+            eprintln!("Failed to read {}: {err}", path.display());
+            eprintln!("Run `aoc bench --run` or `cargo bench -p aoc` to measure");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut medians = BTreeMap::new();
+    for record in records {
+        medians.insert((record.day, record.phase, record.input), record.median);
+    }
+
+    println!(
+        "{:>3}  {:>6}  {:>10}  {:>10}",
+        "Day", "Phase", "Example", "Input"
+    );
+    for day in registry::DAYS {
+        for phase in Phase::ALL {
+            let [example, real] = InputKind::ALL.map(|input| {
+                medians
+                    .get(&(day.number, phase, input))
+                    .map_or("-".to_string(), |median| format!("{median:.2?}"))
+            });
+
+            if example != "-" || real != "-" {
+                println!("{:>3}  {phase:>6}  {example:>10}  {real:>10}", day.number);
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
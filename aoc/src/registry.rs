@@ -0,0 +1,253 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use serde::{Serialize, Serializer};
+
+use crate::stats::Allocations;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err("part must be either 1 or 2"),
+        }
+    }
+}
+
+/// Serialized as the number of the part.
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// Reason a part has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartError {
+    /// The solution found that the input has no answer.
+    Solve(SolveError),
+    /// The solution panicked with this message.
+    Panic(String),
+}
+
+impl Display for PartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartError::Solve(error) => write!(f, "{error}"),
+            PartError::Panic(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// Result of solving a single part.
+pub struct PartOutcome {
+    pub part: Part,
+    pub answer: Result<Answer, PartError>,
+    pub elapsed: Duration,
+    pub allocations: Allocations,
+}
+
+/// Result of parsing the input once and solving requested parts on it.
+pub struct Outcome {
+    pub parse_time: Duration,
+    pub parse_allocations: Allocations,
+    pub parts: Vec<PartOutcome>,
+}
+
+/// Type-erased entry point into a single day's [`Solution`].
+pub struct Day {
+    pub number: u8,
+    solve: fn(&str, &[Part]) -> Result<Outcome, ParseError>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8) -> Self {
+        Self {
+            number,
+            solve: solve::<S>,
+        }
+    }
+
+    /// Parses `input` and solves each of `parts` on it.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Outcome, ParseError> {
+        (self.solve)(input, parts)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Outcome, ParseError> {
+    let start = Instant::now();
+    let (parsed, parse_allocations) = Allocations::track(|| S::parse(input));
+    let parse_time = start.elapsed();
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, allocations) = Allocations::track(|| {
+                match panic::catch_unwind(AssertUnwindSafe(|| match part {
+                    Part::One => S::part_1(&parsed),
+                    Part::Two => S::part_2(&parsed),
+                })) {
+                    Ok(answer) => answer.map_err(PartError::Solve),
+                    Err(payload) => Err(PartError::Panic(panic_message(&*payload))),
+                }
+            });
+
+            PartOutcome {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+                allocations,
+            }
+        })
+        .collect();
+
+    Ok(Outcome {
+        parse_time,
+        parse_allocations,
+        parts,
+    })
+}
+
+/// Message that a caught panic was raised with.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Receives every registered [`Solution`] with its concrete type, e.g. to benchmark
+/// parsing separately from solving.
+pub trait Visitor {
+    fn visit<S: Solution>(&mut self, number: u8);
+}
+
+macro_rules! solutions {
+    ($($number:literal => $solution:ty),* $(,)?) => {
+        pub static DAYS: &[Day] = &[$(Day::new::<$solution>($number)),*];
+
+        /// Calls `visitor` with every registered solution, in order of days.
+        pub fn visit(visitor: &mut impl Visitor) {
+            $(visitor.visit::<$solution>($number);)*
+        }
+    };
+}
+
+// Generated by `build.rs` from the `dayN` dependencies of this crate.
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// Parts that take minutes to solve on the real input.
+pub const SLOW: [(u8, Part); 1] = [(12, Part::Two)];
+
+pub fn is_slow(number: u8, part: Part) -> bool {
+    SLOW.contains(&(number, part))
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn find_day() {
+        assert_eq!(find(7).map(|day| day.number), Some(7));
+        assert!(find(0).is_none());
+        assert!(find(26).is_none());
+    }
+
+    #[test]
+    fn visit_every_day() {
+        struct Numbers(Vec<u8>);
+
+        impl Visitor for Numbers {
+            fn visit<S: Solution>(&mut self, number: u8) {
+                self.0.push(number);
+            }
+        }
+
+        let mut numbers = Numbers(Vec::new());
+        visit(&mut numbers);
+
+        assert_eq!(
+            numbers.0,
+            DAYS.iter().map(|day| day.number).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn solve_through_registry() {
+        let day = find(6).unwrap();
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+
+        let outcome = day.solve(input, &Part::ALL).unwrap();
+        let answers = outcome
+            .parts
+            .into_iter()
+            .map(|part| part.answer)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            answers,
+            vec![Ok(Answer::from(288u64)), Ok(Answer::from(71503u64))]
+        );
+    }
+
+    #[test]
+    fn keep_solve_errors_apart_from_panics() {
+        let outcome = find(13).unwrap().solve("#.\n..", &Part::ALL).unwrap();
+
+        assert_eq!(
+            outcome.parts[0].answer,
+            Err(PartError::Solve(SolveError::not_found(
+                "line of reflection in pattern 1"
+            )))
+        );
+    }
+}
@@ -0,0 +1,507 @@
+//! Results of solving days as a table, JSON or CSV, to compare them across runs.
+
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    panic,
+    str::FromStr,
+    time::Duration,
+};
+
+use aoc_core::{ParseError, SolveError};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use serde::{Serialize, Serializer};
+
+use crate::registry::{self, Day, Outcome, Part, PartError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err("format must be one of table, json or csv"),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Table => f.pad("table"),
+            Format::Json => f.pad("json"),
+            Format::Csv => f.pad("csv"),
+        }
+    }
+}
+
+/// Reason a part has no answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum Failure {
+    /// The puzzle input could not be read.
+    Input { message: String },
+    /// The puzzle input does not match the format the day expects.
+    Parse {
+        message: String,
+        line: usize,
+        column: usize,
+    },
+    /// The puzzle input has no answer, possibly because of what is at `line` and `column`.
+    Solve {
+        message: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        line: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        column: Option<usize>,
+    },
+    /// Parsing or solving panicked.
+    Panic { message: String },
+    /// The part is slow to solve and was left out.
+    Skipped { message: String },
+}
+
+impl Failure {
+    pub fn message(&self) -> &str {
+        match self {
+            Failure::Input { message }
+            | Failure::Parse { message, .. }
+            | Failure::Solve { message, .. }
+            | Failure::Panic { message }
+            | Failure::Skipped { message } => message,
+        }
+    }
+
+    /// One-based line and column of the input the failure points at, if any.
+    pub fn position(&self) -> Option<(usize, usize)> {
+        match *self {
+            Failure::Parse { line, column, .. } => Some((line, column)),
+            Failure::Solve {
+                line: Some(line),
+                column: Some(column),
+                ..
+            } => Some((line, column)),
+            _ => None,
+        }
+    }
+}
+
+impl From<&ParseError> for Failure {
+    fn from(error: &ParseError) -> Self {
+        Failure::Parse {
+            message: format!("expected {}", error.expected()),
+            line: error.line(),
+            column: error.column(),
+        }
+    }
+}
+
+impl From<&SolveError> for Failure {
+    fn from(error: &SolveError) -> Self {
+        match error {
+            SolveError::Parse(error) => Failure::from(error),
+            SolveError::Invalid {
+                line,
+                column,
+                reason,
+            } => Failure::Solve {
+                message: reason.clone(),
+                line: Some(*line),
+                column: Some(*column),
+            },
+            error => Failure::Solve {
+                message: error.to_string(),
+                line: None,
+                column: None,
+            },
+        }
+    }
+}
+
+/// Result of solving a single part, with times in seconds once serialized.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<String>,
+    /// Time to parse the input, shared by all parts solved on it.
+    #[serde(serialize_with = "seconds")]
+    pub parse_time: Option<Duration>,
+    #[serde(serialize_with = "seconds")]
+    pub solve_time: Option<Duration>,
+    pub error: Option<Failure>,
+}
+
+fn seconds<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => serializer.serialize_some(&duration.as_secs_f64()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Turns the outcome of solving `parts` of `day`, or the reason it could not be solved,
+/// into a record for each part.
+pub fn records(day: u8, parts: &[Part], outcome: Result<Outcome, Failure>) -> Vec<Record> {
+    match outcome {
+        Ok(outcome) => outcome
+            .parts
+            .into_iter()
+            .map(|solved| Record {
+                day,
+                part: solved.part,
+                answer: solved.answer.as_ref().ok().map(|answer| answer.to_string()),
+                error: solved.answer.err().map(|err| match err {
+                    PartError::Solve(err) => Failure::from(&err),
+                    PartError::Panic(message) => Failure::Panic {
+                        message: format!("part {} panicked: {message}", solved.part),
+                    },
+                }),
+                parse_time: Some(outcome.parse_time),
+                solve_time: Some(solved.elapsed),
+            })
+            .collect(),
+        Err(failure) => parts
+            .iter()
+            .map(|&part| Record {
+                day,
+                part,
+                answer: None,
+                parse_time: None,
+                solve_time: None,
+                error: Some(failure.clone()),
+            })
+            .collect(),
+    }
+}
+
+/// Solves `parts` of `day` on `input`, catching parse and solve errors and panics.
+pub fn solve(day: &Day, input: io::Result<String>, parts: &[Part]) -> Vec<Record> {
+    let outcome = match input {
+        Ok(input) => match panic::catch_unwind(|| day.solve(&input, parts)) {
+            Ok(Ok(outcome)) => Ok(outcome),
+            Ok(Err(err)) => Err(Failure::from(&err)),
+            Err(payload) => Err(Failure::Panic {
+                message: format!("parsing panicked: {}", registry::panic_message(&*payload)),
+            }),
+        },
+        Err(err) => Err(Failure::Input {
+            message: err.to_string(),
+        }),
+    };
+
+    records(day.number, parts, outcome)
+}
+
+/// Solves both parts of every one of `days` on the input returned by `input` for its
+/// number, parsing it once for both, each day on its own thread of a pool unless `serial`,
+/// and sorts the records by day and part.
+///
+/// Parts that are [slow](registry::is_slow) are only solved if `slow`, and are otherwise
+/// recorded as [skipped](Failure::Skipped) without reading the input of a day that has
+/// nothing else to solve. A part that panics or a day that fails to parse does not stop
+/// the others.
+pub fn solve_all<'a>(
+    days: impl IntoIterator<Item = &'a Day>,
+    serial: bool,
+    slow: bool,
+    input: impl Fn(u8) -> io::Result<String> + Sync,
+) -> Vec<Record> {
+    let days = days.into_iter().collect::<Vec<_>>();
+    let solve_day = |&day: &&Day| {
+        let (parts, skipped): (Vec<_>, Vec<_>) = Part::ALL
+            .into_iter()
+            .partition(|&part| slow || !registry::is_slow(day.number, part));
+
+        let mut day_records = if parts.is_empty() {
+            Vec::new()
+        } else {
+            solve(day, input(day.number), &parts)
+        };
+        let skip = Failure::Skipped {
+            message: "skipped as slow to solve".to_string(),
+        };
+        day_records.extend(records(day.number, &skipped, Err(skip)));
+
+        day_records
+    };
+
+    let mut records: Vec<Record> = if serial {
+        days.iter().flat_map(solve_day).collect()
+    } else {
+        days.par_iter().flat_map_iter(solve_day).collect()
+    };
+    records.sort_by_key(|record| (record.day, record.part));
+
+    records
+}
+
+/// Flattened [`Record`] for CSV, which has no nested values.
+#[derive(Serialize)]
+struct Row<'a> {
+    day: u8,
+    part: Part,
+    answer: Option<&'a str>,
+    parse_time: Option<f64>,
+    solve_time: Option<f64>,
+    error: Option<&'a str>,
+    error_line: Option<usize>,
+    error_column: Option<usize>,
+}
+
+impl<'a> From<&'a Record> for Row<'a> {
+    fn from(record: &'a Record) -> Self {
+        let position = record.error.as_ref().and_then(Failure::position);
+
+        Self {
+            day: record.day,
+            part: record.part,
+            answer: record.answer.as_deref(),
+            parse_time: record.parse_time.map(|time| time.as_secs_f64()),
+            solve_time: record.solve_time.map(|time| time.as_secs_f64()),
+            error: record.error.as_ref().map(Failure::message),
+            error_line: position.map(|(line, _)| line),
+            error_column: position.map(|(_, column)| column),
+        }
+    }
+}
+
+fn write_table(records: &[Record], mut out: impl Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:>3}  {:>4}  {:>20}  {:>10}  {:>10}  Error",
+        "Day", "Part", "Answer", "Parse", "Solve"
+    )?;
+
+    let time = |time: Option<Duration>| time.map_or("-".to_string(), |time| format!("{time:.2?}"));
+    for record in records {
+        let error = match &record.error {
+            Some(failure) => match failure.position() {
+                Some((line, column)) => {
+                    format!("{} at line {line}, column {column}", failure.message())
+                }
+                None => failure.message().to_string(),
+            },
+            None => String::new(),
+        };
+
+        let row = format!(
+            "{:>3}  {:>4}  {:>20}  {:>10}  {:>10}  {error}",
+            record.day,
+            record.part,
+            record.answer.as_deref().unwrap_or("-"),
+            time(record.parse_time),
+            time(record.solve_time),
+        );
+        writeln!(out, "{}", row.trim_end())?;
+    }
+
+    Ok(())
+}
+
+/// Writes `records` to `out` in `format`.
+pub fn write(format: Format, records: &[Record], mut out: impl Write) -> io::Result<()> {
+    match format {
+        Format::Table => write_table(records, out),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut out, records)?;
+            writeln!(out)
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for record in records {
+                writer.serialize(Row::from(record))?;
+            }
+            writer.flush()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use serde_json::json;
+
+    use super::*;
+
+    const INPUT: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+    fn output(format: Format, records: &[Record]) -> String {
+        let mut out = Vec::new();
+        write(format, records, &mut out).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("table".parse(), Ok(Format::Table));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn solve_into_records() {
+        let records = solve(
+            registry::find(6).unwrap(),
+            Ok(INPUT.to_string()),
+            &Part::ALL,
+        );
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].part, Part::Two);
+        assert_eq!(records[1].answer.as_deref(), Some("71503"));
+        assert_eq!(records[0].parse_time, records[1].parse_time);
+        assert!(records.iter().all(|record| record.error.is_none()));
+    }
+
+    #[test]
+    fn solve_all_despite_failures() {
+        let days = [6, 17, 3].map(|number| registry::find(number).unwrap());
+        let reads = AtomicUsize::new(0);
+        let input = |day| {
+            reads.fetch_add(1, Ordering::Relaxed);
+            match day {
+                6 => Ok(INPUT.to_string()),
+                // There is no way to go at least four blocks straight.
+                17 => Ok("12".to_string()),
+                _ => Err(io::Error::from(io::ErrorKind::NotFound)),
+            }
+        };
+
+        for serial in [false, true] {
+            reads.store(0, Ordering::Relaxed);
+            let records = solve_all(days, serial, false, input);
+
+            // Both parts of a day are solved on the same parsed input.
+            assert_eq!(reads.load(Ordering::Relaxed), days.len());
+            assert_eq!(records[2].parse_time, records[3].parse_time);
+
+            let summary = records
+                .iter()
+                .map(|record| (record.day, record.part, record.answer.as_deref()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                summary,
+                [
+                    (3, Part::One, None),
+                    (3, Part::Two, None),
+                    (6, Part::One, Some("288")),
+                    (6, Part::Two, Some("71503")),
+                    (17, Part::One, Some("2")),
+                    (17, Part::Two, None),
+                ]
+            );
+            assert!(matches!(records[0].error, Some(Failure::Input { .. })));
+            assert_eq!(
+                records[5].error,
+                Some(Failure::Solve {
+                    message: "found no path from the top left to the bottom right corner"
+                        .to_string(),
+                    line: None,
+                    column: None,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn skip_slow_parts() {
+        let days = [registry::find(12).unwrap()];
+        let input = |_| Ok("???.### 1,1,3".to_string());
+
+        let records = solve_all(days, true, false, input);
+        assert_eq!(records[0].answer.as_deref(), Some("1"));
+        assert_eq!(records[1].part, Part::Two);
+        assert!(matches!(records[1].error, Some(Failure::Skipped { .. })));
+        assert_eq!(records[1].solve_time, None);
+
+        let records = solve_all(days, true, true, input);
+        assert_eq!(records[1].answer.as_deref(), Some("1"));
+        assert_eq!(records[1].error, None);
+    }
+
+    #[test]
+    fn report_parse_error_position() {
+        let input = "Time: 7\nDistance: x".to_string();
+        let records = solve(registry::find(6).unwrap(), Ok(input), &[Part::One]);
+
+        let json: serde_json::Value =
+            serde_json::from_str(&output(Format::Json, &records)).unwrap();
+        assert_eq!(
+            json,
+            json!([{
+                "day": 6,
+                "part": 1,
+                "answer": null,
+                "parse_time": null,
+                "solve_time": null,
+                "error": {"kind": "parse", "message": "expected digit", "line": 2, "column": 11},
+            }])
+        );
+    }
+
+    #[test]
+    fn report_solve_error_position() {
+        let map = "S-7\n|.|\nL.J".to_string();
+        let records = solve(registry::find(10).unwrap(), Ok(map), &[Part::Two]);
+
+        assert_eq!(
+            records[0].error,
+            Some(Failure::Solve {
+                message: "Empty cannot be entered going West".to_string(),
+                line: Some(3),
+                column: Some(2),
+            })
+        );
+        assert!(output(Format::Table, &records)
+            .ends_with("Empty cannot be entered going West at line 3, column 2\n"));
+        assert!(
+            output(Format::Csv, &records).ends_with(",Empty cannot be entered going West,3,2\n")
+        );
+    }
+
+    #[test]
+    fn write_csv() {
+        let records = vec![
+            Record {
+                day: 6,
+                part: Part::One,
+                answer: Some("288".to_string()),
+                parse_time: Some(Duration::from_micros(15)),
+                solve_time: Some(Duration::from_millis(2)),
+                error: None,
+            },
+            Record {
+                day: 7,
+                part: Part::Two,
+                answer: None,
+                parse_time: None,
+                solve_time: None,
+                error: Some(Failure::Parse {
+                    message: "expected hand, bid".to_string(),
+                    line: 3,
+                    column: 1,
+                }),
+            },
+        ];
+
+        assert_eq!(
+            output(Format::Csv, &records),
+            "day,part,answer,parse_time,solve_time,error,error_line,error_column
+6,1,288,0.000015,0.002,,,
+7,2,,,,\"expected hand, bid\",3,1
+"
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! HTTP service solving puzzle inputs posted to it, for tools that cannot run `aoc` itself.
+//!
+//! - `GET /days` lists the registered days with their parts.
+//! - `POST /solve/{day}/{part}` solves the puzzle input in the body, responding with the
+//!   `answer`, the `duration` of parsing and solving in seconds, and an `error` if there
+//!   is no answer.
+
+use std::{
+    io,
+    net::{SocketAddr, ToSocketAddrs},
+    panic, str, thread,
+};
+
+use aoc_core::input;
+use serde::Serialize;
+use tiny_http::{Header, Method, Request, Response};
+
+use crate::registry::{self, Part, PartError};
+
+#[derive(Debug, Serialize)]
+struct DayInfo {
+    day: u8,
+    parts: [Part; 2],
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Solved {
+    answer: Option<String>,
+    duration: Option<f64>,
+    error: Option<String>,
+}
+
+impl Solved {
+    fn error(error: impl Into<String>) -> Self {
+        Self {
+            error: Some(error.into()),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Error {
+    error: String,
+}
+
+/// Status code and JSON body of a response.
+type Reply = (u16, String);
+
+fn reply(status: u16, body: &impl Serialize) -> Reply {
+    let json = serde_json::to_string(body).expect("responses serialize to JSON");
+
+    (status, json)
+}
+
+fn days() -> Reply {
+    let days = registry::DAYS
+        .iter()
+        .map(|day| DayInfo {
+            day: day.number,
+            parts: Part::ALL,
+        })
+        .collect::<Vec<_>>();
+
+    reply(200, &days)
+}
+
+fn solve(day: &str, part: &str, body: &[u8]) -> Reply {
+    let Some(solution) = day.parse().ok().and_then(registry::find) else {
+        return reply(404, &Solved::error(format!("day {day} is not implemented")));
+    };
+    let Ok(part) = part.parse::<Part>() else {
+        return reply(404, &Solved::error("part must be either 1 or 2"));
+    };
+    let Ok(contents) = str::from_utf8(body) else {
+        return reply(400, &Solved::error("puzzle input is not valid UTF-8"));
+    };
+    let contents = input::normalize(contents);
+
+    let outcome = match panic::catch_unwind(|| solution.solve(&contents, &[part])) {
+        Ok(Ok(outcome)) => outcome,
+        Ok(Err(err)) => {
+            return reply(
+                422,
+                &Solved::error(format!("failed to parse puzzle input: {err}")),
+            );
+        }
+        Err(payload) => {
+            let message = registry::panic_message(&*payload);
+            return reply(500, &Solved::error(format!("parsing panicked: {message}")));
+        }
+    };
+
+    let solved = &outcome.parts[0];
+    let duration = Some((outcome.parse_time + solved.elapsed).as_secs_f64());
+    match &solved.answer {
+        Ok(answer) => reply(
+            200,
+            &Solved {
+                answer: Some(answer.to_string()),
+                duration,
+                error: None,
+            },
+        ),
+        Err(PartError::Solve(err)) => reply(
+            422,
+            &Solved {
+                duration,
+                ..Solved::error(format!(
+                    "part {part} has no answer: {}",
+                    err.in_input(&contents)
+                ))
+            },
+        ),
+        Err(PartError::Panic(message)) => reply(
+            500,
+            &Solved {
+                duration,
+                ..Solved::error(format!("part {part} panicked: {message}"))
+            },
+        ),
+    }
+}
+
+fn route(method: &Method, url: &str, body: &[u8]) -> Reply {
+    let path = url.split_once('?').map_or(url, |(path, _)| path);
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+    match (method, segments.as_slice()) {
+        (Method::Get, ["days"]) => days(),
+        (Method::Post, ["solve", day, part]) => solve(day, part, body),
+        (_, ["days"] | ["solve", _, _]) => reply(
+            405,
+            &Error {
+                error: format!("{method} is not allowed on {path}"),
+            },
+        ),
+        _ => reply(
+            404,
+            &Error {
+                error: format!("{path} not found"),
+            },
+        ),
+    }
+}
+
+fn handle(mut request: Request) {
+    let mut body = Vec::new();
+    let (status, json) = match request.as_reader().read_to_end(&mut body) {
+        Ok(_) => route(request.method(), request.url(), &body),
+        Err(err) => reply(
+            400,
+            &Error {
+                error: format!("failed to read request: {err}"),
+            },
+        ),
+    };
+    log::debug!("{} {} -> {status}", request.method(), request.url());
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json)
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(err) = request.respond(response) {
+        log::warn!("Failed to respond: {err}");
+    }
+}
+
+/// Solution service listening on a TCP socket.
+pub struct Server {
+    http: tiny_http::Server,
+}
+
+impl Server {
+    /// Listens on `addr`, where port 0 picks any free port.
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let http = tiny_http::Server::http(addr).map_err(io::Error::other)?;
+
+        Ok(Self { http })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.http
+            .server_addr()
+            .to_ip()
+            .expect("server listens on a TCP socket")
+    }
+
+    /// Answers requests until the process exits, each on its own thread so that slow parts
+    /// do not hold up other requests.
+    pub fn run(&self) {
+        for request in self.http.incoming_requests() {
+            thread::spawn(|| handle(request));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const INPUT: &str = "Time:      7  15   30\nDistance:  9  40  200";
+
+    fn request(method: Method, url: &str, body: &str) -> (u16, Value) {
+        let (status, json) = route(&method, url, body.as_bytes());
+
+        (status, serde_json::from_str(&json).unwrap())
+    }
+
+    #[test]
+    fn list_days() {
+        let (status, days) = request(Method::Get, "/days", "");
+
+        assert_eq!(status, 200);
+        assert_eq!(days[0], json!({"day": 1, "parts": [1, 2]}));
+        assert_eq!(days.as_array().unwrap().len(), registry::DAYS.len());
+    }
+
+    #[test]
+    fn solve_part() {
+        let (status, solved) = request(Method::Post, "/solve/6/2", INPUT);
+
+        assert_eq!(status, 200);
+        assert_eq!(solved["answer"], "71503");
+        assert!(solved["duration"].is_f64());
+        assert_eq!(solved["error"], Value::Null);
+    }
+
+    #[test]
+    fn report_parse_error() {
+        let (status, solved) = request(Method::Post, "/solve/6/1", "Time: 7\nDistance: x");
+
+        assert_eq!(status, 422);
+        assert_eq!(solved["answer"], Value::Null);
+        assert!(solved["error"]
+            .as_str()
+            .unwrap()
+            .contains("at line 2, column 11"));
+    }
+
+    #[test]
+    fn report_solve_error() {
+        let (status, solved) = request(Method::Post, "/solve/13/1", "#.\n..");
+
+        assert_eq!(status, 422);
+        assert_eq!(
+            solved["error"],
+            "part 1 has no answer: found no line of reflection in pattern 1"
+        );
+        assert!(solved["duration"].is_f64());
+    }
+
+    #[test]
+    fn reject_unknown_routes() {
+        assert_eq!(request(Method::Post, "/solve/26/1", INPUT).0, 404);
+        assert_eq!(request(Method::Post, "/solve/6/3", INPUT).0, 404);
+        assert_eq!(request(Method::Get, "/solve/6/1", INPUT).0, 405);
+        assert_eq!(request(Method::Get, "/answers", "").0, 404);
+    }
+}
@@ -0,0 +1,147 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static TOTAL_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator that counts allocations made through it.
+///
+/// Without it installed with `#[global_allocator]`, [`Allocations::track`] reports zeros.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        let size = size as u64;
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        TOTAL_BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        LIVE_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage of a single phase of a solve.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub total_bytes: u64,
+    /// Largest amount of memory that was allocated at once, on top of what was already
+    /// allocated when the phase started.
+    pub peak_bytes: u64,
+}
+
+impl Allocations {
+    /// Runs `f` and records the allocations it made.
+    ///
+    /// Allocations made concurrently by other threads are counted as well.
+    pub fn track<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+        let count = COUNT.load(Ordering::Relaxed);
+        let total_bytes = TOTAL_BYTES.load(Ordering::Relaxed);
+        let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(live_bytes, Ordering::Relaxed);
+
+        let value = f();
+
+        let allocations = Allocations {
+            count: COUNT.load(Ordering::Relaxed) - count,
+            total_bytes: TOTAL_BYTES.load(Ordering::Relaxed) - total_bytes,
+            peak_bytes: PEAK_BYTES
+                .load(Ordering::Relaxed)
+                .saturating_sub(live_bytes),
+        };
+
+        (value, allocations)
+    }
+}
+
+/// Number of bytes displayed with a binary unit, e.g. `1.50 MiB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return f.pad(&format!("{} B", self.0));
+        }
+
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        f.pad(&format!("{value:.2} {}", UNITS[unit]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn track_allocations() {
+        let (_, allocations) = Allocations::track(|| {
+            let small = vec![0u8; 1000];
+            drop(small);
+            vec![0u8; 4000]
+        });
+
+        assert!(allocations.count >= 2);
+        assert!(allocations.total_bytes >= 5000);
+        assert!(allocations.peak_bytes >= 4000);
+    }
+
+    #[test]
+    fn display_bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.00 MiB");
+        assert_eq!(format!("{:>10}", Bytes(2048)), "  2.00 KiB");
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use crate::{
+    answers::Answers,
+    registry::{Day, Part},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Incorrect,
+    /// The part was solved, but there is no recorded answer to compare against.
+    Unrecorded,
+    /// The input could not be parsed, has no answer or the solution panicked.
+    Failed,
+}
+
+impl Status {
+    pub fn is_ok(self) -> bool {
+        matches!(self, Status::Correct | Status::Unrecorded)
+    }
+}
+
+/// Result of re-solving a single part and comparing it with the recorded answer.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub expected: Option<String>,
+    /// Answer produced by the solution, or the reason it could not be produced.
+    pub actual: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+impl Check {
+    pub fn status(&self) -> Status {
+        match (&self.expected, &self.actual) {
+            (_, Err(_)) => Status::Failed,
+            (None, Ok(_)) => Status::Unrecorded,
+            (Some(expected), Ok(actual)) if expected == actual => Status::Correct,
+            (Some(_), Ok(_)) => Status::Incorrect,
+        }
+    }
+}
+
+/// Solves `parts` of `day` on `input` and compares them with recorded `answers`.
+pub fn verify(day: &Day, input: &str, answers: &Answers, parts: &[Part]) -> Vec<Check> {
+    let check = |part, actual, elapsed| Check {
+        day: day.number,
+        part,
+        expected: answers.get(day.number, part),
+        actual,
+        elapsed,
+    };
+
+    let outcome = match panic::catch_unwind(AssertUnwindSafe(|| day.solve(input, parts))) {
+        Ok(Ok(outcome)) => outcome,
+        Ok(Err(err)) => {
+            let reason = format!("parse error at {}:{}", err.line(), err.column());
+            return parts
+                .iter()
+                .map(|&part| check(part, Err(reason.clone()), Duration::ZERO))
+                .collect();
+        }
+        Err(_) => {
+            return parts
+                .iter()
+                .map(|&part| check(part, Err("parser panicked".to_string()), Duration::ZERO))
+                .collect();
+        }
+    };
+
+    outcome
+        .parts
+        .into_iter()
+        .map(|part| {
+            let actual = part
+                .answer
+                .map(|answer| answer.to_string())
+                .map_err(|err| err.to_string());
+
+            check(part.part, actual, part.elapsed)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    use super::*;
+
+    const INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn compare_with_recorded_answers() {
+        let answers = "[day6]\npart_1 = 288\npart_2 = 1".parse().unwrap();
+
+        let checks = verify(registry::find(6).unwrap(), INPUT, &answers, &Part::ALL);
+
+        let statuses = checks.iter().map(Check::status).collect::<Vec<_>>();
+        assert_eq!(statuses, vec![Status::Correct, Status::Incorrect]);
+        assert_eq!(checks[1].actual.as_deref(), Ok("71503"));
+    }
+
+    #[test]
+    fn report_unrecorded_and_failed_parts() {
+        let answers = Answers::default();
+        let day = registry::find(6).unwrap();
+
+        let checks = verify(day, INPUT, &answers, &[Part::One]);
+        assert_eq!(checks[0].status(), Status::Unrecorded);
+
+        let checks = verify(day, "Time: x", &answers, &Part::ALL);
+        assert!(checks.iter().all(|check| check.status() == Status::Failed));
+    }
+}
@@ -0,0 +1,53 @@
+use aoc_core::SolveError;
+use aoc_viz::Animation;
+
+use crate::registry::Part;
+
+/// Draws how a part is solved for the given puzzle input.
+pub type Visualize = fn(&str) -> Result<Animation, SolveError>;
+
+/// Parts of days that can be visualised.
+pub const VISUALIZATIONS: [(u8, Part, Visualize); 7] = [
+    (10, Part::Two, day10::visualize_part_2),
+    (14, Part::One, day14::visualize_part_1),
+    (14, Part::Two, day14::visualize_part_2),
+    (16, Part::One, day16::visualize_part_1),
+    (16, Part::Two, day16::visualize_part_2),
+    (17, Part::One, day17::visualize_part_1),
+    (17, Part::Two, day17::visualize_part_2),
+];
+
+pub fn find(day: u8, part: Part) -> Option<Visualize> {
+    VISUALIZATIONS
+        .iter()
+        .find(|&&(number, visualized, _)| (number, visualized) == (day, part))
+        .map(|&(_, _, visualize)| visualize)
+}
+
+/// Parts of `day` that can be visualised.
+pub fn parts(day: u8) -> Vec<Part> {
+    VISUALIZATIONS
+        .iter()
+        .filter(|&&(number, _, _)| number == day)
+        .map(|&(_, part, _)| part)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visualize_examples() {
+        for (day, part, visualize) in VISUALIZATIONS {
+            let input = aoc_core::input::read(crate::example_path(day, part)).unwrap();
+            let animation = visualize(&input).unwrap();
+
+            assert!(!animation.is_empty(), "day {day} part {part}");
+            assert!(find(day, part).is_some());
+        }
+
+        assert_eq!(parts(16), vec![Part::One, Part::Two]);
+        assert!(find(1, Part::One).is_none());
+    }
+}
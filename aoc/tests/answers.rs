@@ -0,0 +1,44 @@
+use aoc::{
+    answers::Answers,
+    registry::{self, Part},
+    verify::{self, Status},
+};
+use aoc_core::input;
+
+fn check_answers(filter: impl Fn(u8, Part) -> bool) {
+    let answers = Answers::load(Answers::default_path()).expect("Failed to load recorded answers");
+
+    let mut failures = Vec::new();
+    for day in registry::DAYS {
+        let parts = Part::ALL
+            .into_iter()
+            .filter(|&part| filter(day.number, part) && answers.get(day.number, part).is_some())
+            .collect::<Vec<_>>();
+        if parts.is_empty() {
+            continue;
+        }
+
+        let input = input::read(aoc::input_path(day.number)).expect("Failed to read puzzle input");
+        for check in verify::verify(day, &input, &answers, &parts) {
+            if check.status() != Status::Correct {
+                failures.push(format!(
+                    "day {} part {}: expected {:?}, got {:?}",
+                    check.day, check.part, check.expected, check.actual
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn answers_match_recorded() {
+    check_answers(|day, part| !registry::is_slow(day, part));
+}
+
+#[test]
+#[ignore = "takes minutes to run"]
+fn slow_answers_match_recorded() {
+    check_answers(registry::is_slow);
+}
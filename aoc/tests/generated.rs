@@ -0,0 +1,51 @@
+//! Solves generated inputs of every day to check that they are valid.
+
+use aoc::{
+    generate::{self, GENERATORS},
+    registry::{self, Part},
+};
+
+#[test]
+fn solve_generated_inputs() {
+    for &(day, _, _) in GENERATORS {
+        let solution = registry::find(day).unwrap();
+        // Slow parts take too long even on small inputs.
+        let parts = Part::ALL
+            .into_iter()
+            .filter(|&part| !registry::is_slow(day, part))
+            .collect::<Vec<_>>();
+
+        for seed in 0..5 {
+            for size in [1, 5, 12] {
+                let input = generate::generate(day, Some(size), seed).unwrap();
+                // Days fresh from the template generate nothing until they are solved.
+                if input.is_empty() {
+                    continue;
+                }
+                let outcome = solution
+                    .solve(&input, &parts)
+                    .unwrap_or_else(|err| panic!("day {day} size {size} seed {seed}: {err}"));
+
+                for part in outcome.parts {
+                    assert!(
+                        part.answer.is_ok(),
+                        "day {day} part {} failed on size {size} seed {seed}:\n{input}",
+                        part.part
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn reproducible_inputs() {
+    for &(day, _, _) in GENERATORS {
+        let input = generate::generate(day, Some(8), 42).unwrap();
+
+        assert_eq!(generate::generate(day, Some(8), 42), Some(input));
+        assert!(registry::find(day).is_some());
+    }
+
+    assert!(generate::generate(25, None, 0).is_none());
+}
@@ -0,0 +1,93 @@
+//! Talks to the solution service over a local socket, the way other tools would.
+
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+    sync::OnceLock,
+    thread,
+};
+
+use aoc::{registry, serve::Server};
+use serde_json::{json, Value};
+
+/// Address of a server shared by all tests, running until the test process exits.
+fn server() -> SocketAddr {
+    static ADDR: OnceLock<SocketAddr> = OnceLock::new();
+
+    *ADDR.get_or_init(|| {
+        let server = Server::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr();
+        thread::spawn(move || server.run());
+        addr
+    })
+}
+
+/// Sends a request and returns the status code and parsed JSON body of the response.
+fn request(method: &str, path: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(server()).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    assert!(
+        head.contains("Content-Type: application/json"),
+        "unexpected headers:\n{head}"
+    );
+
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn list_days() {
+    let (status, days) = request("GET", "/days", "");
+
+    assert_eq!(status, 200);
+    let days = days.as_array().unwrap();
+    assert_eq!(days.len(), registry::DAYS.len());
+    assert!(days.contains(&json!({"day": 6, "parts": [1, 2]})));
+}
+
+#[test]
+fn solve_example() {
+    let input = include_str!("../../day6/example.txt");
+
+    let (status, solved) = request("POST", "/solve/6/1", input);
+
+    assert_eq!(status, 200);
+    assert_eq!(solved["answer"], "288");
+    assert!(solved["duration"].as_f64().unwrap() >= 0.0);
+    assert_eq!(solved["error"], Value::Null);
+}
+
+#[test]
+fn solve_with_crlf_line_endings() {
+    let input = include_str!("../../day6/example.txt").replace('\n', "\r\n");
+
+    let (status, solved) = request("POST", "/solve/6/2", &input);
+
+    assert_eq!(status, 200);
+    assert_eq!(solved["answer"], "71503");
+}
+
+#[test]
+fn report_errors() {
+    let (status, solved) = request("POST", "/solve/3/1", "467..\n..*x");
+    assert_eq!(status, 422);
+    assert_eq!(solved["answer"], Value::Null);
+    assert!(solved["error"].as_str().unwrap().contains("line 2"));
+
+    let (status, solved) = request("POST", "/solve/25/1", "");
+    assert_eq!(status, 404);
+    assert_eq!(solved["error"], "day 25 is not implemented");
+
+    assert_eq!(request("GET", "/solve/6/1", "").0, 405);
+    assert_eq!(request("GET", "/", "").0, 404);
+}
@@ -0,0 +1,33 @@
+use aoc_gen::{weighted, Rng, SliceRandom};
+
+use crate::SPELLED_DIGITS;
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 1000;
+
+/// Generates `size` lines of calibration values mixing letters, digits and spelled out digits.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| calibration_line(rng))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn calibration_line(rng: &mut impl Rng) -> String {
+    let mut line = String::new();
+    for _ in 0..rng.gen_range(1..=8) {
+        match weighted(rng, &[(0, 4), (1, 2), (2, 2)]) {
+            0 => line.push(rng.gen_range(b'a'..=b'z') as char),
+            1 => line.push(rng.gen_range(b'1'..=b'9') as char),
+            _ => line.push_str(SPELLED_DIGITS.choose(rng).unwrap()),
+        }
+    }
+
+    // Every line needs at least one digit for the first part.
+    if !line.chars().any(|char| char.is_ascii_digit()) {
+        let position = rng.gen_range(0..=line.len());
+        line.insert(position, rng.gen_range(b'1'..=b'9') as char);
+    }
+
+    line
+}
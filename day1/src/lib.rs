@@ -1,18 +1,30 @@
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
+pub use generate::{generate, INPUT_SIZE};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 use itertools::Itertools;
 use parser::parse_input;
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let lines = parse_input(input);
+fn no_digit(line_idx: usize) -> SolveError {
+    SolveError::not_found(format!("digit on line {}", line_idx + 1))
+}
 
+fn part_1(lines: &[&str]) -> Result<u32, SolveError> {
     lines
-        .into_iter()
-        .map(|line| {
-            let first_digit = line.chars().find(char::is_ascii_digit).unwrap();
-            let last_digit = line.chars().rev().find(char::is_ascii_digit).unwrap();
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let first_digit = line.chars().find(char::is_ascii_digit);
+            let last_digit = line.chars().rev().find(char::is_ascii_digit);
+            let (Some(first_digit), Some(last_digit)) = (first_digit, last_digit) else {
+                return Err(no_digit(idx));
+            };
             let number_str = format!("{}{}", first_digit, last_digit);
-            number_str.parse::<u32>().unwrap()
+            Ok(number_str.parse::<u32>().unwrap())
         })
         .sum()
 }
@@ -21,7 +33,7 @@ const SPELLED_DIGITS: [&str; 9] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-fn get_first_digit(line: &str) -> char {
+fn get_first_digit(line: &str) -> Option<char> {
     let first_ascii_digit_opt = line.chars().find_position(char::is_ascii_digit);
 
     let fist_spelled_digit_opt = SPELLED_DIGITS
@@ -35,7 +47,7 @@ fn get_first_digit(line: &str) -> char {
         })
         .min_by_key(|&(idx, _)| idx);
 
-    match (first_ascii_digit_opt, fist_spelled_digit_opt) {
+    let digit = match (first_ascii_digit_opt, fist_spelled_digit_opt) {
         (Some((_, ch)), None) => ch,
         (None, Some((_, ch))) => ch,
         (Some((ascii_idx, ascii_char)), Some((spelled_idx, spelled_char))) => {
@@ -45,11 +57,13 @@ fn get_first_digit(line: &str) -> char {
                 spelled_char
             }
         }
-        _ => unreachable!(),
-    }
+        (None, None) => return None,
+    };
+
+    Some(digit)
 }
 
-fn get_last_digit(line: &str) -> char {
+fn get_last_digit(line: &str) -> Option<char> {
     let first_ascii_digit_opt = line
         .chars()
         .enumerate()
@@ -69,7 +83,7 @@ fn get_last_digit(line: &str) -> char {
         })
         .max_by_key(|&(idx, _)| idx);
 
-    match (first_ascii_digit_opt, fist_spelled_digit_opt) {
+    let digit = match (first_ascii_digit_opt, fist_spelled_digit_opt) {
         (Some((_, ch)), None) => ch,
         (None, Some((_, ch))) => ch,
         (Some((ascii_idx, ascii_char)), Some((spelled_idx, spelled_char))) => {
@@ -79,25 +93,52 @@ fn get_last_digit(line: &str) -> char {
                 spelled_char
             }
         }
-        _ => unreachable!(),
-    }
-}
+        (None, None) => return None,
+    };
 
-pub fn solve_part_2(input: &str) -> u32 {
-    let lines = parse_input(input);
+    Some(digit)
+}
 
+fn part_2(lines: &[&str]) -> Result<u32, SolveError> {
     lines
-        .into_iter()
-        .map(|line| {
-            let first_digit = get_first_digit(line);
-            let last_digit = get_last_digit(line);
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let first_digit = get_first_digit(line).ok_or_else(|| no_digit(idx))?;
+            let last_digit = get_last_digit(line).ok_or_else(|| no_digit(idx))?;
 
             let number_str = format!("{}{}", first_digit, last_digit);
-            number_str.parse::<u32>().unwrap()
+            Ok(number_str.parse::<u32>().unwrap())
         })
         .sum()
 }
 
+pub fn solve_part_1(input: &str) -> Result<u32, SolveError> {
+    part_1(&parse_input(input))
+}
+
+pub fn solve_part_2(input: &str) -> Result<u32, SolveError> {
+    part_2(&parse_input(input))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part_1(lines: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(lines).map(Answer::from)
+    }
+
+    fn part_2(lines: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(lines).map(Answer::from)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,42 +146,52 @@ mod tests {
     #[test]
     fn test_get_first_digit() {
         let line1 = "abc1def2ghi";
-        assert_eq!(get_first_digit(line1), '1');
+        assert_eq!(get_first_digit(line1), Some('1'));
 
         let line2 = "abconed3eftwoghi";
-        assert_eq!(get_first_digit(line2), '1');
+        assert_eq!(get_first_digit(line2), Some('1'));
 
         let line3 = "twone";
-        assert_eq!(get_first_digit(line3), '2');
-
-        assert_eq!(get_first_digit("two1nine"), '2');
-        assert_eq!(get_first_digit("eightwothree"), '8');
-        assert_eq!(get_first_digit("abcone2threexyz"), '1');
-        assert_eq!(get_first_digit("xtwone3four"), '2');
-        assert_eq!(get_first_digit("4nineeightseven2"), '4');
-        assert_eq!(get_first_digit("zoneight234"), '1');
-        assert_eq!(get_first_digit("7pqrstsixteen"), '7');
-        assert_eq!(get_first_digit("eightwothree"), '8');
+        assert_eq!(get_first_digit(line3), Some('2'));
+
+        assert_eq!(get_first_digit("two1nine"), Some('2'));
+        assert_eq!(get_first_digit("eightwothree"), Some('8'));
+        assert_eq!(get_first_digit("abcone2threexyz"), Some('1'));
+        assert_eq!(get_first_digit("xtwone3four"), Some('2'));
+        assert_eq!(get_first_digit("4nineeightseven2"), Some('4'));
+        assert_eq!(get_first_digit("zoneight234"), Some('1'));
+        assert_eq!(get_first_digit("7pqrstsixteen"), Some('7'));
+        assert_eq!(get_first_digit("eightwothree"), Some('8'));
     }
 
     #[test]
     fn test_get_last_digit() {
         let line1 = "abc1def2ghi";
-        assert_eq!(get_last_digit(line1), '2');
+        assert_eq!(get_last_digit(line1), Some('2'));
 
         let line2 = "abconed3eftwoghi";
-        assert_eq!(get_last_digit(line2), '2');
+        assert_eq!(get_last_digit(line2), Some('2'));
 
         let line3 = "twone";
-        assert_eq!(get_last_digit(line3), '1');
-
-        assert_eq!(get_last_digit("two1nine"), '9');
-        assert_eq!(get_last_digit("eightwothree"), '3');
-        assert_eq!(get_last_digit("abcone2threexyz"), '3');
-        assert_eq!(get_last_digit("xtwone3four"), '4');
-        assert_eq!(get_last_digit("4nineeightseven2"), '2');
-        assert_eq!(get_last_digit("zoneight234"), '4');
-        assert_eq!(get_last_digit("7pqrstsixteen"), '6');
-        assert_eq!(get_last_digit("eightwothree"), '3');
+        assert_eq!(get_last_digit(line3), Some('1'));
+
+        assert_eq!(get_last_digit("two1nine"), Some('9'));
+        assert_eq!(get_last_digit("eightwothree"), Some('3'));
+        assert_eq!(get_last_digit("abcone2threexyz"), Some('3'));
+        assert_eq!(get_last_digit("xtwone3four"), Some('4'));
+        assert_eq!(get_last_digit("4nineeightseven2"), Some('2'));
+        assert_eq!(get_last_digit("zoneight234"), Some('4'));
+        assert_eq!(get_last_digit("7pqrstsixteen"), Some('6'));
+        assert_eq!(get_last_digit("eightwothree"), Some('3'));
+    }
+
+    #[test]
+    fn report_line_without_digits() {
+        assert_eq!(get_first_digit("abc"), None);
+        assert_eq!(part_1(&["1abc2", "pqr"]), Err(no_digit(1)));
+        assert_eq!(
+            part_2(&["two1nine", "xyz"]).unwrap_err().to_string(),
+            "found no digit on line 2"
+        );
     }
 }
@@ -1,43 +1,51 @@
-use std::fs;
+use std::{env, process::ExitCode};
 
+use aoc_core::{input, Solution};
 use day1::*;
 
-fn main() {
-    let contents = fs::read_to_string("day1/input.txt").expect("Failed to read puzzle input");
+fn main() -> ExitCode {
+    let contents = input::load(env!("CARGO_MANIFEST_DIR"), env::args_os().nth(1))
+        .expect("Failed to read puzzle input");
+    let input = match Day1::parse(&contents) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match Day1::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Part 1: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day1::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Part 2: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    ExitCode::SUCCESS
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
+    const INPUT: &str = include_str!("../example.txt");
 
-    const INPUT2: &str = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
+    const INPUT2: &str = include_str!("../example2.txt");
 
     #[test]
     fn test_part_1() {
         let sol = solve_part_1(INPUT);
 
-        assert_eq!(sol, 142);
+        assert_eq!(sol, Ok(142));
     }
 
     #[test]
     fn test_part_2() {
         let sol = solve_part_2(INPUT2);
 
-        assert_eq!(sol, 281);
+        assert_eq!(sol, Ok(281));
     }
 }
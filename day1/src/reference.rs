@@ -0,0 +1,56 @@
+//! Calibration values from the digits found at every position of a line, spelled out or not.
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Digits of `line` at each position, including spelled out ones if `spelled`.
+fn digits(line: &str, spelled: bool) -> Vec<u32> {
+    (0..line.len())
+        .filter_map(|index| {
+            let rest = &line[index..];
+            let digit = rest.chars().next().and_then(|char| char.to_digit(10));
+            let word =
+                (1..=9).find(|&digit| spelled && rest.starts_with(WORDS[digit as usize - 1]));
+
+            digit.or(word)
+        })
+        .collect()
+}
+
+fn calibration_sum(input: &str, spelled: bool) -> u32 {
+    input
+        .lines()
+        .map(|line| {
+            let digits = digits(line, spelled);
+            10 * digits[0] + digits[digits.len() - 1]
+        })
+        .sum()
+}
+
+pub fn part_1(input: &str) -> u32 {
+    calibration_sum(input, false)
+}
+
+pub fn part_2(input: &str) -> u32 {
+    calibration_sum(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..20) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
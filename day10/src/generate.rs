@@ -0,0 +1,188 @@
+use aoc_gen::{weighted, Rng, SliceRandom};
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_search::flood_fill;
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 140;
+
+/// Generates a map of about `size` by `size` tiles with a single closed loop of pipes among junk pipes.
+///
+/// The loop is the outline of a random region of a coarse grid, one coarse cell being two
+/// tiles wide. Regions without holes or cells touching only by their corners have outlines
+/// that never cross or touch themselves.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let cells = (size.max(3) - 1) / 2;
+    let region = region(cells, rng);
+    let side = 2 * cells + 1;
+
+    // Connections of the tiles of the loop, in the order of `Direction::ALL`.
+    let mut connections: Grid<[bool; 4]> = Grid::filled(side, side, [false; 4]);
+    for ((x, y), &inside) in region.iter_indices().zip(region.iter()) {
+        if !inside {
+            continue;
+        }
+
+        for direction in Direction::all() {
+            let outside = region
+                .step(Pos::new(x, y), direction)
+                .is_none_or(|neighbor| !region[neighbor]);
+            if outside {
+                connect_side(&mut connections, Pos::new(2 * x + 1, 2 * y + 1), direction);
+            }
+        }
+    }
+
+    let on_loop = |pos: Pos| connections[pos].contains(&true);
+    let loop_tiles = connections
+        .iter_indices()
+        .map(|(x, y)| Pos::new(x, y))
+        .filter(|&pos| on_loop(pos))
+        .collect::<Vec<_>>();
+    let start = *loop_tiles.choose(rng).unwrap();
+
+    let mut tiles = Grid::filled(side, side, '.');
+    for ((x, y), tile) in connections.iter_indices().zip(connections.iter()) {
+        let pos = Pos::new(x, y);
+        tiles[pos] = if pos == start {
+            'S'
+        } else if on_loop(pos) {
+            pipe(*tile)
+        } else if pos.manhattan_distance(start) == 1 {
+            // Junk pipes next to the start could make its pipe ambiguous.
+            '.'
+        } else {
+            weighted(
+                rng,
+                &[
+                    ('.', 6),
+                    ('|', 1),
+                    ('-', 1),
+                    ('L', 1),
+                    ('J', 1),
+                    ('7', 1),
+                    ('F', 1),
+                ],
+            )
+        };
+    }
+
+    tiles.to_string().trim_end_matches('\n').to_string()
+}
+
+/// Picks a random region of a `cells` by `cells` grid without holes or pinches.
+fn region(cells: usize, rng: &mut impl Rng) -> Grid<bool> {
+    let mut region = Grid::filled(cells, cells, false);
+    let first = Pos::new(rng.gen_range(0..cells), rng.gen_range(0..cells));
+    region[first] = true;
+
+    let target = rng.gen_range(1..=(cells * cells * 2 / 3).max(1));
+    let mut area = 1;
+    let mut attempts = 0;
+    while area < target && attempts < 20 * cells * cells {
+        attempts += 1;
+
+        let candidates = region
+            .iter_indices()
+            .map(|(x, y)| Pos::new(x, y))
+            .filter(|&pos| {
+                !region[pos]
+                    && Direction::all()
+                        .filter_map(|direction| region.step(pos, direction))
+                        .any(|neighbor| region[neighbor])
+            })
+            .collect::<Vec<_>>();
+        let Some(&candidate) = candidates.choose(rng) else {
+            break;
+        };
+
+        region[candidate] = true;
+        if is_simple(&region) {
+            area += 1;
+        } else {
+            region[candidate] = false;
+        }
+    }
+
+    region
+}
+
+/// Whether the region has no holes and no cells touching only by a corner.
+fn is_simple(region: &Grid<bool>) -> bool {
+    let pinched = (0..region.height.saturating_sub(1))
+        .flat_map(|y| (0..region.width - 1).map(move |x| (x, y)))
+        .any(|(x, y)| {
+            let [a, b, c, d] = [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]
+                .map(|(x, y)| region[Pos::new(x, y)]);
+            a == d && b == c && a != b
+        });
+    if pinched {
+        return false;
+    }
+
+    // Every cell outside of the region must be reachable from the border of the grid.
+    let border = region
+        .iter_indices()
+        .map(|(x, y)| Pos::new(x, y))
+        .filter(|&pos| {
+            !region[pos] && Direction::all().any(|direction| region.step(pos, direction).is_none())
+        });
+    let outside = flood_fill(border, |&pos| {
+        Direction::all()
+            .filter_map(|direction| region.step(pos, direction))
+            .filter(|&neighbor| !region[neighbor])
+            .collect::<Vec<_>>()
+    });
+
+    outside.len() == region.iter().filter(|&&inside| !inside).count()
+}
+
+/// Connects the three tiles along the side of the cell centered on `center` facing
+/// `direction`.
+fn connect_side(connections: &mut Grid<[bool; 4]>, center: Pos, direction: Direction) {
+    let middle = center.checked_add(direction.offset()).unwrap();
+    let along = [direction.turn_left(), direction.turn_right()];
+
+    for turn in along {
+        let corner = middle.checked_add(turn.offset()).unwrap();
+        connections[middle][turn as usize] = true;
+        connections[corner][turn.opposite() as usize] = true;
+    }
+}
+
+fn pipe([north, east, south, west]: [bool; 4]) -> char {
+    match (north, east, south, west) {
+        (true, false, true, false) => '|',
+        (false, true, false, true) => '-',
+        (true, true, false, false) => 'L',
+        (true, false, false, true) => 'J',
+        (false, false, true, true) => '7',
+        (false, true, true, false) => 'F',
+        connections => unreachable!("loop tile with connections {connections:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+
+    use super::*;
+    use crate::{Color, Map};
+
+    #[test]
+    fn single_loop() {
+        for seed in 0..20 {
+            let input = generate(15, &mut rng(seed));
+            assert_eq!(input.lines().count(), 15);
+
+            // Only the tiles of the loop are colored black, so the loop is closed and the start
+            // connects to the right pipes.
+            let map: Map = input.parse().unwrap();
+            let colors = crate::color_map(&map).unwrap();
+            let loop_len = colors
+                .iter()
+                .filter(|&&color| color == Some(Color::Black))
+                .count();
+            assert_eq!(crate::part_1(&map), Ok(loop_len / 2));
+        }
+    }
+}
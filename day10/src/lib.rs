@@ -1,18 +1,16 @@
-use std::{
-    collections::{HashSet, VecDeque},
-    fmt::Display,
-    str::FromStr,
-};
+mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
-use itertools::Itertools;
+pub use generate::{generate, INPUT_SIZE};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
+use std::str::FromStr;
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_search::flood_fill;
+use aoc_viz::{self as viz, Animation, Cell, Frame, Overlay, Palette, Style};
+use itertools::Itertools;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Pipe {
@@ -29,7 +27,7 @@ impl Pipe {
     fn is_connected_to(&self, direction: Direction) -> bool {
         match direction {
             Direction::North => matches!(self, Pipe::NorthEast | Pipe::NorthWest | Pipe::Vertical),
-            Direction::East => matches!(self, Pipe::SouthEast | Pipe::NorthWest | Pipe::Horizontal),
+            Direction::East => matches!(self, Pipe::SouthEast | Pipe::NorthEast | Pipe::Horizontal),
             Direction::South => matches!(self, Pipe::SouthEast | Pipe::SouthWest | Pipe::Vertical),
             Direction::West => matches!(self, Pipe::NorthWest | Pipe::SouthWest | Pipe::Horizontal),
         }
@@ -50,93 +48,55 @@ impl TryFrom<char> for Pipe {
             '7' => Ok(SouthWest),
             'F' => Ok(SouthEast),
             '.' => Ok(Empty),
-            a => {
-                dbg!(a);
-                Err("Invalid character in the map")
-            }
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Position {
-    x: usize,
-    y: usize,
-}
-
-impl Position {
-    fn offset(&self, offset_x: isize, offset_y: isize) -> Self {
-        Self {
-            x: if offset_x < 0 {
-                self.x - offset_x.unsigned_abs()
-            } else {
-                self.x + offset_x as usize
-            },
-            y: if offset_y < 0 {
-                self.y - offset_y.unsigned_abs()
-            } else {
-                self.y + offset_y as usize
-            },
+            _ => Err("Invalid character in the map"),
         }
     }
 }
 
-struct Grid<T> {
-    width: usize,
-    height: usize,
-    tiles: Vec<T>,
-}
-
-impl<T: Copy> Grid<T> {
-    fn get(&self, position: Position) -> T {
-        self.tiles[position.x + position.y * self.width]
-    }
-
-    fn get_mut(&mut self, position: Position) -> &mut T {
-        &mut self.tiles[position.x + position.y * self.width]
-    }
-}
-
-struct Map {
-    start: Position,
+pub struct Map {
+    start: Pos,
     grid: Grid<Pipe>,
 }
 
 impl Map {
     fn get_start(&self) -> Pipe {
-        self.grid.get(self.start)
+        self.grid[self.start]
+    }
+
+    /// Error for a start tile that was not replaced with the pipe connecting it to the loop.
+    fn empty_start(&self) -> SolveError {
+        SolveError::at_position(self.start.y, self.start.x, "start tile is not a pipe")
     }
 }
 
 impl FromStr for Map {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let lines = input.lines().collect_vec();
-        let width = lines[0].len();
-        let height = lines.len();
+        let chars: Grid<char> = input.parse()?;
 
-        let mut tiles = Vec::with_capacity(width * height);
-
-        let mut start_idx = 0;
-        for (idx, char) in lines.into_iter().flat_map(str::chars).enumerate() {
+        let mut start = None;
+        let mut tiles = Vec::with_capacity(chars.width * chars.height);
+        for ((x, y), &char) in chars.iter_indices().zip(chars.iter()) {
             if char == 'S' {
-                start_idx = idx;
+                if start.is_some() {
+                    return Err(ParseError::at_position(input, y, x, "single start tile"));
+                }
+
+                start = Some(Pos::new(x, y));
                 tiles.push(Pipe::Empty);
             } else {
-                tiles.push(char.try_into()?);
+                let pipe = Pipe::try_from(char)
+                    .map_err(|_| ParseError::at_position(input, y, x, "pipe or ground tile"))?;
+                tiles.push(pipe);
             }
         }
 
-        let start = Position {
-            x: start_idx % width,
-            y: start_idx / width,
-        };
+        let start = start.ok_or_else(|| ParseError::at_offset(input, input.len(), "start tile"))?;
+        let mut grid = Grid::new(chars.width, chars.height, tiles);
 
-        let north_pipe = (start.y > 0).then(|| tiles[start_idx - width]);
-        let east_pipe = (start.x < width - 1).then(|| tiles[start_idx + 1]);
-        let south_pipe = (start.y < width - 1).then(|| tiles[start_idx + width]);
-        let west_pipe = (start.x > 0).then(|| tiles[start_idx - 1]);
+        let [north_pipe, east_pipe, south_pipe, west_pipe] =
+            Direction::ALL.map(|direction| grid.step(start, direction).map(|pos| grid[pos]));
 
         use Pipe::*;
         let start_pipe = match (north_pipe, east_pipe, south_pipe, west_pipe) {
@@ -176,57 +136,60 @@ impl FromStr for Map {
             {
                 SouthEast
             }
-            _ => return Err("Invalid start pipe"),
+            _ => {
+                return Err(ParseError::at_position(
+                    input,
+                    start.y,
+                    start.x,
+                    "start tile connected to two pipes",
+                ))
+            }
         };
 
-        tiles[start_idx] = start_pipe;
+        grid[start] = start_pipe;
 
-        Ok(Self {
-            start,
-            grid: Grid {
-                width,
-                height,
-                tiles,
-            },
-        })
+        Ok(Self { start, grid })
     }
 }
 
-fn move_in_direction(mut position: Position, direction: Direction) -> Position {
-    match direction {
-        Direction::North => position.y -= 1,
-        Direction::East => position.x += 1,
-        Direction::South => position.y += 1,
-        Direction::West => position.x -= 1,
-    }
+/// Error for a loop that cannot be followed any further at `position`.
+fn broken_loop(position: Pos, reason: String) -> SolveError {
+    SolveError::at_position(position.y, position.x, reason)
+}
 
-    position
+fn cannot_enter(pipe: Pipe, position: Pos, direction: Direction) -> SolveError {
+    broken_loop(position, format!("{pipe:?} cannot be entered going {direction:?}"))
 }
 
-fn outgoing_direction(pipe: Pipe, incoming_direction: Direction) -> Direction {
-    match (pipe, incoming_direction) {
-        (Pipe::Vertical, Direction::North) => Direction::North,
-        (Pipe::Vertical, Direction::South) => Direction::South,
-        (Pipe::Horizontal, Direction::West) => Direction::West,
-        (Pipe::NorthEast, Direction::South) => Direction::East,
-        (Pipe::NorthEast, Direction::West) => Direction::North,
-        (Pipe::NorthWest, Direction::South) => Direction::West,
-        (Pipe::NorthWest, Direction::East) => Direction::North,
-        (Pipe::SouthWest, Direction::North) => Direction::West,
-        (Pipe::SouthWest, Direction::East) => Direction::South,
-        (Pipe::SouthEast, Direction::North) => Direction::East,
-        (Pipe::SouthEast, Direction::West) => Direction::South,
-        (Pipe::Horizontal, Direction::East) => Direction::East,
-        a => {
-            dbg!(a);
-            unreachable!()
-        }
-    }
+fn move_in_direction(map: &Map, position: Pos, direction: Direction) -> Result<Pos, SolveError> {
+    map.grid
+        .step(position, direction)
+        .ok_or_else(|| broken_loop(position, format!("loop leaves the map going {direction:?}")))
 }
 
-pub fn solve_part_1(input: &str) -> usize {
-    let map: Map = input.parse().unwrap();
+fn outgoing_direction(
+    map: &Map,
+    position: Pos,
+    incoming_direction: Direction,
+) -> Result<Direction, SolveError> {
+    match (map.grid[position], incoming_direction) {
+        (Pipe::Vertical, Direction::North) => Ok(Direction::North),
+        (Pipe::Vertical, Direction::South) => Ok(Direction::South),
+        (Pipe::Horizontal, Direction::West) => Ok(Direction::West),
+        (Pipe::NorthEast, Direction::South) => Ok(Direction::East),
+        (Pipe::NorthEast, Direction::West) => Ok(Direction::North),
+        (Pipe::NorthWest, Direction::South) => Ok(Direction::West),
+        (Pipe::NorthWest, Direction::East) => Ok(Direction::North),
+        (Pipe::SouthWest, Direction::North) => Ok(Direction::West),
+        (Pipe::SouthWest, Direction::East) => Ok(Direction::South),
+        (Pipe::SouthEast, Direction::North) => Ok(Direction::East),
+        (Pipe::SouthEast, Direction::West) => Ok(Direction::South),
+        (Pipe::Horizontal, Direction::East) => Ok(Direction::East),
+        (pipe, direction) => Err(cannot_enter(pipe, position, direction)),
+    }
+}
 
+fn part_1(map: &Map) -> Result<usize, SolveError> {
     let start_pipe = map.get_start();
 
     // `A`` path goes in "clockwise" direction along the pipe.
@@ -238,7 +201,7 @@ pub fn solve_part_1(input: &str) -> usize {
         Pipe::NorthWest => Direction::North,
         Pipe::SouthWest => Direction::South,
         Pipe::SouthEast => Direction::South,
-        Pipe::Empty => unreachable!(),
+        Pipe::Empty => return Err(map.empty_start()),
     };
 
     // `B`` path goes in "anti-clockwise" direction along the pipe.
@@ -250,7 +213,7 @@ pub fn solve_part_1(input: &str) -> usize {
         Pipe::NorthWest => Direction::West,
         Pipe::SouthWest => Direction::West,
         Pipe::SouthEast => Direction::East,
-        Pipe::Empty => unreachable!(),
+        Pipe::Empty => return Err(map.empty_start()),
     };
 
     let mut length = 0;
@@ -258,8 +221,8 @@ pub fn solve_part_1(input: &str) -> usize {
         length += 1;
 
         // Take one step along `A` and `B` paths.
-        position_a = move_in_direction(position_a, direction_a);
-        position_b = move_in_direction(position_b, direction_b);
+        position_a = move_in_direction(map, position_a, direction_a)?;
+        position_b = move_in_direction(map, position_b, direction_b)?;
 
         // If `A` and `B` meet, we traversed the whole loop.
         if position_a == position_b {
@@ -267,11 +230,11 @@ pub fn solve_part_1(input: &str) -> usize {
         }
 
         // Pick next direction based on incoming direction and shape of the pipe.
-        direction_a = outgoing_direction(map.grid.get(position_a), direction_a);
-        direction_b = outgoing_direction(map.grid.get(position_b), direction_b);
+        direction_a = outgoing_direction(map, position_a, direction_a)?;
+        direction_b = outgoing_direction(map, position_b, direction_b)?;
     }
 
-    length
+    Ok(length)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -281,180 +244,205 @@ enum Color {
     Black,
 }
 
-impl Display for Grid<Option<Color>> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let char = match self.get(Position { x, y }) {
-                    None => ' ',
-                    Some(Color::Green) => 'O',
-                    Some(Color::Red) => '.',
-                    Some(Color::Black) => '+',
-                };
-                write!(f, "{}", char)?;
-            }
-
-            writeln!(f)?;
-        }
+const PALETTE: Palette = Palette {
+    tile: viz::Color::rgb(70, 70, 80),
+    accent: viz::Color::WHITE,
+    ..Palette::DEFAULT
+};
 
-        Ok(())
+fn pipe_char(pipe: Pipe) -> char {
+    match pipe {
+        Pipe::Empty => '.',
+        Pipe::Vertical => '│',
+        Pipe::Horizontal => '─',
+        Pipe::NorthEast => '└',
+        Pipe::NorthWest => '┘',
+        Pipe::SouthWest => '┐',
+        Pipe::SouthEast => '┌',
     }
 }
 
+/// Draws the pipes with the loop in bold, tiles enclosed by it as `I` and tiles next to it on
+/// the outside as `O`.
+fn draw_map(map: &Map, colors: &Grid<Option<Color>>) -> Frame {
+    let colored = |color| {
+        colors
+            .iter_indices()
+            .zip(colors.iter())
+            .filter(move |&(_, &tile_color)| tile_color == Some(color))
+            .map(|(position, _)| Pos::from(position))
+    };
+
+    let inside = Style::fg(viz::Color::GREEN).bold();
+    let outside = Style::fg(viz::Color::RED);
+
+    Frame::new(&map.grid, |&pipe| Cell::new(pipe_char(pipe), Style::fg(PALETTE.tile)))
+        .with(&Overlay::new(colored(Color::Black), Style::fg(PALETTE.accent).bold()))
+        .with(&Overlay::new(colored(Color::Green), inside).with_glyph('I'))
+        .with(&Overlay::new(colored(Color::Red), outside).with_glyph('O'))
+        .with(&PALETTE.highlight([map.start]))
+}
+
+/// Colors of the north, east, south and west neighbors of the loop at `position`, entered going
+/// `incoming_direction`.
 fn neighbors_colors(
-    pipe: Pipe,
+    map: &Map,
+    position: Pos,
     incoming_direction: Direction,
-) -> (Option<Color>, Option<Color>, Option<Color>, Option<Color>) {
-    match (pipe, incoming_direction) {
-        (Pipe::Vertical, Direction::North) => (None, Some(Color::Green), None, Some(Color::Red)),
-        (Pipe::Vertical, Direction::South) => (None, Some(Color::Red), None, Some(Color::Green)),
-        (Pipe::Horizontal, Direction::East) => (Some(Color::Red), None, Some(Color::Green), None),
-        (Pipe::Horizontal, Direction::West) => (Some(Color::Green), None, Some(Color::Red), None),
-        (Pipe::NorthEast, Direction::South) => (None, None, Some(Color::Green), Some(Color::Green)),
-        (Pipe::NorthEast, Direction::West) => (None, None, Some(Color::Red), Some(Color::Red)),
-        (Pipe::NorthWest, Direction::South) => (None, Some(Color::Red), Some(Color::Red), None),
-        (Pipe::NorthWest, Direction::East) => (None, Some(Color::Green), Some(Color::Green), None),
-        (Pipe::SouthWest, Direction::North) => (Some(Color::Green), Some(Color::Green), None, None),
-        (Pipe::SouthWest, Direction::East) => (Some(Color::Red), Some(Color::Red), None, None),
-        (Pipe::SouthEast, Direction::North) => (Some(Color::Red), None, None, Some(Color::Red)),
-        (Pipe::SouthEast, Direction::West) => (Some(Color::Green), None, None, Some(Color::Green)),
-        a => {
-            dbg!(a);
-            unreachable!()
+) -> Result<[Option<Color>; 4], SolveError> {
+    let colors = match (map.grid[position], incoming_direction) {
+        (Pipe::Vertical, Direction::North) => [None, Some(Color::Green), None, Some(Color::Red)],
+        (Pipe::Vertical, Direction::South) => [None, Some(Color::Red), None, Some(Color::Green)],
+        (Pipe::Horizontal, Direction::East) => [Some(Color::Red), None, Some(Color::Green), None],
+        (Pipe::Horizontal, Direction::West) => [Some(Color::Green), None, Some(Color::Red), None],
+        (Pipe::NorthEast, Direction::South) => [None, None, Some(Color::Green), Some(Color::Green)],
+        (Pipe::NorthEast, Direction::West) => [None, None, Some(Color::Red), Some(Color::Red)],
+        (Pipe::NorthWest, Direction::South) => [None, Some(Color::Red), Some(Color::Red), None],
+        (Pipe::NorthWest, Direction::East) => [None, Some(Color::Green), Some(Color::Green), None],
+        (Pipe::SouthWest, Direction::North) => [Some(Color::Green), Some(Color::Green), None, None],
+        (Pipe::SouthWest, Direction::East) => [Some(Color::Red), Some(Color::Red), None, None],
+        (Pipe::SouthEast, Direction::North) => [Some(Color::Red), None, None, Some(Color::Red)],
+        (Pipe::SouthEast, Direction::West) => [Some(Color::Green), None, None, Some(Color::Green)],
+        (pipe, direction) => return Err(cannot_enter(pipe, position, direction)),
+    };
+
+    Ok(colors)
+}
+
+/// Right turns minus left turns taken following the loop from the start, entering it going
+/// `direction`; 4 for clockwise loops and -4 for anticlockwise ones.
+fn net_right_turns(map: &Map, mut direction: Direction) -> Result<i32, SolveError> {
+    let mut position = map.start;
+    let mut turns = 0;
+    loop {
+        let outgoing = outgoing_direction(map, position, direction)?;
+        if outgoing == direction.turn_right() {
+            turns += 1;
+        } else if outgoing == direction.turn_left() {
+            turns -= 1;
+        }
+
+        direction = outgoing;
+        position = move_in_direction(map, position, direction)?;
+        if position == map.start {
+            return Ok(turns);
         }
     }
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let map: Map = input.parse().unwrap();
-
+/// Colors tiles of the loop black, tiles enclosed by it green and some of the tiles outside
+/// of it red.
+fn color_map(map: &Map) -> Result<Grid<Option<Color>>, SolveError> {
     let start_pipe = map.get_start();
 
-    let mut position = map.start;
-    let mut direction = match start_pipe {
-        Pipe::Vertical => Direction::North,
-        Pipe::Horizontal => Direction::East,
-        Pipe::NorthEast => Direction::West,
-        Pipe::NorthWest => Direction::South,
-        Pipe::SouthWest => Direction::East,
-        Pipe::SouthEast => Direction::North,
-        Pipe::Empty => unreachable!(),
+    // Tiles on the right of the way the loop is followed are colored green, so it must be
+    // followed clockwise for them to be inside.
+    let (forward, backward) = match start_pipe {
+        Pipe::Vertical => (Direction::North, Direction::South),
+        Pipe::Horizontal => (Direction::East, Direction::West),
+        Pipe::NorthEast => (Direction::West, Direction::South),
+        Pipe::NorthWest => (Direction::South, Direction::East),
+        Pipe::SouthWest => (Direction::East, Direction::North),
+        Pipe::SouthEast => (Direction::North, Direction::West),
+        Pipe::Empty => return Err(map.empty_start()),
     };
-
-    let mut colors: Grid<Option<Color>> = Grid {
-        width: map.grid.width,
-        height: map.grid.height,
-        tiles: vec![None; map.grid.width * map.grid.height],
+    let mut direction = if net_right_turns(map, forward)? > 0 {
+        forward
+    } else {
+        backward
     };
+    let mut position = map.start;
+
+    let mut colors: Grid<Option<Color>> = Grid::filled(map.grid.width, map.grid.height, None);
 
     loop {
-        *colors.get_mut(position) = Some(Color::Black);
-
-        let (north_color, east_color, south_color, west_color) =
-            neighbors_colors(map.grid.get(position), direction);
-
-        (position.y > 0 && colors.get(position.offset(0, -1)).is_none()).then(|| {
-            north_color.map(|color| {
-                let current_color = colors.get_mut(position.offset(0, -1));
-                if let Some(current_color) = current_color {
-                    assert_eq!(*current_color, color);
-                } else {
-                    *current_color = Some(color);
-                }
-            })
-        });
-        (position.x < colors.width - 1 && colors.get(position.offset(1, 0)).is_none()).then(
-            || {
-                east_color.map(|color| {
-                    let current_color = colors.get_mut(position.offset(1, 0));
-                    if let Some(current_color) = current_color {
-                        assert_eq!(*current_color, color);
-                    } else {
-                        *current_color = Some(color);
-                    }
-                })
-            },
-        );
-        (position.y < colors.height - 1 && colors.get(position.offset(0, 1)).is_none())
-            .then(|| {
-                south_color.map(|color| {
-                    let current_color = colors.get_mut(position.offset(0, 1));
-                    if let Some(current_color) = current_color {
-                        assert_eq!(*current_color, color);
-                    } else {
-                        *current_color = Some(color);
-                    }
-                })
-            });
-        (position.x > 0 && colors.get(position.offset(-1, 0)).is_none()).then(|| {
-            west_color.map(|color| {
-                let current_color = colors.get_mut(position.offset(-1, 0));
-                if let Some(current_color) = current_color {
-                    assert_eq!(*current_color, color);
-                } else {
-                    *current_color = Some(color);
-                }
-            })
-        });
+        colors[position] = Some(Color::Black);
+
+        let neighbors_colors = neighbors_colors(map, position, direction)?;
 
-        direction = outgoing_direction(map.grid.get(position), direction);
-        position = move_in_direction(position, direction);
+        for (neighbor_direction, color) in Direction::all().zip(neighbors_colors) {
+            let Some(neighbor) = colors.step(position, neighbor_direction) else {
+                continue;
+            };
+            if colors[neighbor].is_none() {
+                colors[neighbor] = color;
+            }
+        }
+
+        direction = outgoing_direction(map, position, direction)?;
+        position = move_in_direction(map, position, direction)?;
         if position == map.start {
             break;
         }
     }
 
-    let mut candidates = VecDeque::new();
-    candidates.extend(
-        (0..colors.height)
-            .cartesian_product(0..colors.width)
-            .filter_map(|(y, x)| {
-                let position = Position { x, y };
-                (colors.get(position) == Some(Color::Green)).then_some(position)
-            }),
-    );
-
-    let mut visited = HashSet::<Position>::new();
-    while let Some(position) = candidates.pop_front() {
-        if colors.get(position) != Some(Color::Black) {
-            if colors.get(position) == Some(Color::Red) {
-                panic!("PANIC!");
-            }
+    let green = (0..colors.height)
+        .cartesian_product(0..colors.width)
+        .map(|(y, x)| Pos::new(x, y))
+        .filter(|&position| colors[position] == Some(Color::Green));
+
+    let inside = flood_fill(green, |&position| {
+        Direction::all()
+            .filter_map(|direction| colors.step(position, direction))
+            .filter(|&neighbor| colors[neighbor] != Some(Color::Black))
+            .collect_vec()
+    });
+
+    // Filling from inside the loop never reaches a tile outside of it, unless the pipes
+    // around the loop were not followed the way they connect.
+    let leaked = inside.iter().find(|&&position| colors[position] == Some(Color::Red));
+    if let Some(position) = leaked {
+        let reason = "tile is both inside and outside the loop";
+        return Err(SolveError::at_position(position.y, position.x, reason));
+    }
 
-            visited.insert(position);
+    for &position in &inside {
+        colors[position] = Some(Color::Green);
+    }
 
-            *colors.get_mut(position) = Some(Color::Green);
+    Ok(colors)
+}
 
-            if let Some(position) = (position.y > 0)
-                .then(|| position.offset(0, -1))
-                .filter(|position| !visited.contains(position))
-            {
-                candidates.push_back(position)
-            }
-            if let Some(position) = (position.x < colors.width - 1)
-                .then(|| position.offset(1, 0))
-                .filter(|position| !visited.contains(position))
-            {
-                candidates.push_back(position)
-            }
-            if let Some(position) = (position.y < colors.height - 1)
-                .then(|| position.offset(0, 1))
-                .filter(|position| !visited.contains(position))
-            {
-                candidates.push_back(position)
-            }
-            if let Some(position) = (position.x > 0)
-                .then(|| position.offset(-1, 0))
-                .filter(|position| !visited.contains(position))
-            {
-                candidates.push_back(position)
-            }
-        }
+fn part_2(map: &Map) -> Result<usize, SolveError> {
+    let colors = color_map(map)?;
+
+    if log::log_enabled!(log::Level::Debug) {
+        log::debug!("Colored map:\n{}", draw_map(map, &colors));
+    }
+
+    Ok(colors.iter().filter(|&&color| color == Some(Color::Green)).count())
+}
+
+pub fn solve_part_1(input: &str) -> Result<usize, SolveError> {
+    part_1(&input.parse()?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<usize, SolveError> {
+    part_2(&input.parse()?)
+}
+
+pub fn visualize_part_2(input: &str) -> Result<Animation, SolveError> {
+    let map = input.parse()?;
+
+    Ok(Animation::from(draw_map(&map, &color_map(&map)?)).with_palette(PALETTE))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
     }
 
-    println!("{}", colors);
+    fn part_1(map: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(map).map(Answer::from)
+    }
 
-    visited.len()
+    fn part_2(map: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(map).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -471,7 +459,7 @@ mod tests {
 
         let map: Map = input.parse().unwrap();
 
-        assert_eq!(map.grid.get(map.start), Pipe::NorthEast);
+        assert_eq!(map.grid[map.start], Pipe::NorthEast);
     }
 
     #[test]
@@ -484,7 +472,7 @@ LJ.LJ";
 
         let map: Map = input.parse().unwrap();
 
-        assert_eq!(map.grid.get(map.start), Pipe::SouthEast);
+        assert_eq!(map.grid[map.start], Pipe::SouthEast);
     }
 
     #[test]
@@ -495,6 +483,61 @@ LJ.LJ";
 
         let map: Map = input.parse().unwrap();
 
-        assert_eq!(map.grid.get(map.start), Pipe::Vertical);
+        assert_eq!(map.grid[map.start], Pipe::Vertical);
+    }
+
+    #[test]
+    fn correctly_replace_start_pipe_4() {
+        let input = "F-7
+|.|
+LSJ";
+
+        let map: Map = input.parse().unwrap();
+
+        assert_eq!(map.grid[map.start], Pipe::Horizontal);
+    }
+
+    #[test]
+    fn count_tiles_enclosed_following_the_loop_either_way() {
+        // From this start the loop is followed anticlockwise.
+        let map: Map = "F-7\n|.S\nL-J".parse().unwrap();
+        assert_eq!(part_2(&map), Ok(1));
+
+        let map: Map = "F-7\n|.|\nLSJ".parse().unwrap();
+        assert_eq!(part_2(&map), Ok(1));
+    }
+
+    #[test]
+    fn report_empty_start() {
+        let mut map: Map = "F-7\n|.|\nLSJ".parse().unwrap();
+        map.grid[map.start] = Pipe::Empty;
+
+        let error = SolveError::at_position(2, 1, "start tile is not a pipe");
+        assert_eq!(part_1(&map), Err(error.clone()));
+        assert_eq!(part_2(&map), Err(error));
+    }
+
+    #[test]
+    fn report_broken_loop() {
+        let map: Map = "S-7\n|.|\nL.J".parse().unwrap();
+
+        assert_eq!(
+            part_1(&map).unwrap_err().to_string(),
+            "Empty cannot be entered going East at line 3, column 2"
+        );
+        assert_eq!(
+            part_2(&map),
+            Err(SolveError::at_position(2, 1, "Empty cannot be entered going West"))
+        );
+    }
+
+    #[test]
+    fn report_invalid_tiles() {
+        let error = ".F7.\n.S|x\n.LJ.".parse::<Map>().err().unwrap();
+        assert_eq!((error.line(), error.column()), (2, 4));
+
+        let error = "....\n.S..\n....".parse::<Map>().err().unwrap();
+        assert_eq!((error.line(), error.column()), (2, 2));
+        assert_eq!(error.expected(), "start tile connected to two pipes");
     }
 }
@@ -1,24 +1,37 @@
-use std::fs;
+use std::{env, process::ExitCode};
 
+use aoc_core::{input, Solution};
 use day10::*;
 
-fn main() {
-    let contents = fs::read_to_string("day10/input.txt").expect("Failed to read puzzle input");
+fn main() -> ExitCode {
+    let contents = input::load(env!("CARGO_MANIFEST_DIR"), env::args_os().nth(1))
+        .expect("Failed to read puzzle input");
+    let input = match Day10::parse(&contents) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day10::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Part 1: {}", err.in_input(&contents)),
+    }
+
+    match Day10::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Part 2: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    ExitCode::SUCCESS
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT1: &str = ".....
-.S-7.
-.|.|.
-.L-J.
-.....";
+    const INPUT1: &str = include_str!("../example.txt");
 
 const INPUT2: &str = "..F7.
 .FJ|.
@@ -49,8 +62,8 @@ L7JLJL-JLJLJL--JLJ.L";
         let sol1 = solve_part_1(INPUT1);
         let sol2 = solve_part_1(INPUT2);
 
-        assert_eq!(sol1, 4);
-        assert_eq!(sol2, 8);
+        assert_eq!(sol1, Ok(4));
+        assert_eq!(sol2, Ok(8));
     }
 
     #[test]
@@ -60,9 +73,9 @@ L7JLJL-JLJLJL--JLJ.L";
         let sol2 = solve_part_2(INPUT2);
         let sol3 = solve_part_2(INPUT3);
 
-        assert_eq!(sol1, 1);
-        assert_eq!(sol1a, 9);
-        assert_eq!(sol2, 1);
-        assert_eq!(sol3, 10);
+        assert_eq!(sol1, Ok(1));
+        assert_eq!(sol1a, Ok(9));
+        assert_eq!(sol2, Ok(1));
+        assert_eq!(sol3, Ok(10));
     }
 }
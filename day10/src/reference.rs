@@ -0,0 +1,114 @@
+//! Pipe loop from following the pipes tile by tile, with enclosed tiles found by casting rays.
+
+use std::collections::HashSet;
+
+/// Whether each pipe connects to the north, east, south and west.
+const CONNECTIONS: [(char, [bool; 4]); 6] = [
+    ('|', [true, false, true, false]),
+    ('-', [false, true, false, true]),
+    ('L', [true, true, false, false]),
+    ('J', [true, false, false, true]),
+    ('7', [false, false, true, true]),
+    ('F', [false, true, true, false]),
+];
+const OFFSETS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Tiles of the map with the start replaced by the pipe connecting its two neighbors.
+fn tiles(input: &str) -> (Vec<Vec<char>>, (usize, usize)) {
+    let mut tiles = input
+        .lines()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let y = tiles.iter().position(|row| row.contains(&'S')).unwrap();
+    let x = tiles[y].iter().position(|&tile| tile == 'S').unwrap();
+
+    let connected = [0, 1, 2, 3].map(|direction| {
+        neighbor(&tiles, (x, y), direction)
+            .is_some_and(|(nx, ny)| connects(tiles[ny][nx], (direction + 2) % 4))
+    });
+    tiles[y][x] = CONNECTIONS
+        .iter()
+        .find(|&&(_, pipe)| pipe == connected)
+        .unwrap()
+        .0;
+
+    (tiles, (x, y))
+}
+
+fn connects(tile: char, direction: usize) -> bool {
+    CONNECTIONS
+        .iter()
+        .any(|&(pipe, connections)| pipe == tile && connections[direction])
+}
+
+fn neighbor(
+    tiles: &[Vec<char>],
+    (x, y): (usize, usize),
+    direction: usize,
+) -> Option<(usize, usize)> {
+    let (dx, dy) = OFFSETS[direction];
+    let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+    (y < tiles.len() && x < tiles[y].len()).then_some((x, y))
+}
+
+/// Tiles of the loop, found by following the pipes from the start.
+fn main_loop(tiles: &[Vec<char>], start: (usize, usize)) -> HashSet<(usize, usize)> {
+    let mut tiles_of_loop = HashSet::from([start]);
+    let mut stack = vec![start];
+    while let Some(pos) = stack.pop() {
+        for direction in 0..4 {
+            if connects(tiles[pos.1][pos.0], direction) {
+                let next = neighbor(tiles, pos, direction).unwrap();
+                if tiles_of_loop.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+    }
+
+    tiles_of_loop
+}
+
+pub fn part_1(input: &str) -> usize {
+    let (tiles, start) = tiles(input);
+
+    main_loop(&tiles, start).len() / 2
+}
+
+/// Counts the tiles with an odd number of pipes of the loop crossing a ray cast to their left.
+pub fn part_2(input: &str) -> usize {
+    let (tiles, start) = tiles(input);
+    let tiles_of_loop = main_loop(&tiles, start);
+
+    (0..tiles.len())
+        .flat_map(|y| (0..tiles[y].len()).map(move |x| (x, y)))
+        .filter(|&(x, y)| {
+            // Only pipes going north cross the ray, so that pipes running along it do not.
+            let crossings = (0..x)
+                .filter(|&left| tiles_of_loop.contains(&(left, y)) && connects(tiles[y][left], 0))
+                .count();
+
+            !tiles_of_loop.contains(&(x, y)) && crossings % 2 == 1
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 3usize..20) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -1,38 +1,25 @@
 use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Position {
-    pub x: usize,
-    pub y: usize,
-}
-
-impl Position {
-    pub fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-
-    pub fn distance(self, other: Self) -> usize {
-        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
-    }
-}
+use aoc_core::ParseError;
+use aoc_grid::Pos;
 
 pub struct GalaxyMap {
-    pub galaxies: Vec<Position>,
+    pub galaxies: Vec<Pos>,
     pub empty_columns: Vec<usize>,
     pub empty_rows: Vec<usize>,
 }
 
 impl FromStr for GalaxyMap {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut galaxies = Vec::new();
         for (y, line) in input.lines().enumerate() {
-            for (x, char) in line.char_indices() {
+            for (x, char) in line.chars().enumerate() {
                 match char {
-                    '#' => galaxies.push(Position::new(x, y)),
+                    '#' => galaxies.push(Pos::new(x, y)),
                     '.' => continue,
-                    _ => return Err("Invalid character encountered in map"),
+                    _ => return Err(ParseError::at_position(input, y, x, "'#' or '.'")),
                 }
             }
         }
@@ -80,18 +67,26 @@ mod tests {
         assert_eq!(
             map.galaxies,
             vec![
-                Position::new(3, 0),
-                Position::new(7, 1),
-                Position::new(0, 2),
-                Position::new(6, 4),
-                Position::new(1, 5),
-                Position::new(9, 6),
-                Position::new(7, 8),
-                Position::new(0, 9),
-                Position::new(4, 9),
+                Pos::new(3, 0),
+                Pos::new(7, 1),
+                Pos::new(0, 2),
+                Pos::new(6, 4),
+                Pos::new(1, 5),
+                Pos::new(9, 6),
+                Pos::new(7, 8),
+                Pos::new(0, 9),
+                Pos::new(4, 9),
             ]
         );
         assert_eq!(map.empty_columns.into_iter().collect_vec(), vec![2, 5, 8],);
         assert_eq!(map.empty_rows.into_iter().collect_vec(), vec![3, 7]);
     }
+
+    #[test]
+    fn report_invalid_character() {
+        let error = "..#\n.x.".parse::<GalaxyMap>().err().unwrap();
+
+        assert_eq!((error.line(), error.column()), (2, 2));
+        assert_eq!(error.expected(), "'#' or '.'");
+    }
 }
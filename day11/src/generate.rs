@@ -0,0 +1,24 @@
+use aoc_gen::{grid, Rng};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 140;
+
+/// Generates a `size` by `size` image of galaxies, with some rows and columns left empty so
+/// that they expand.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(2);
+    let empty_rows = (0..size).map(|_| rng.gen_bool(0.15)).collect::<Vec<_>>();
+    let empty_columns = (0..size).map(|_| rng.gen_bool(0.15)).collect::<Vec<_>>();
+
+    let mut index = 0;
+    grid(size, size, rng, |rng| {
+        let (x, y) = (index % size, index / size);
+        index += 1;
+
+        if !empty_rows[y] && !empty_columns[x] && rng.gen_bool(0.1) {
+            '#'
+        } else {
+            '.'
+        }
+    })
+}
@@ -1,16 +1,21 @@
 mod galaxy_map;
+mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
+pub use generate::{generate, INPUT_SIZE};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 use galaxy_map::*;
 use itertools::Itertools;
 
-pub fn solve_part_1(input: &str) -> usize {
-    let map: GalaxyMap = input.parse().unwrap();
-
+fn part_1(map: &GalaxyMap) -> u64 {
     map.galaxies
-        .into_iter()
+        .iter()
+        .copied()
         .tuple_combinations()
         .map(|(galaxy1, galaxy2)| {
-            let distance = galaxy1.distance(galaxy2);
+            let distance = galaxy1.manhattan_distance(galaxy2);
 
             let min_x = galaxy1.x.min(galaxy2.x);
             let max_x = galaxy1.x.max(galaxy2.x);
@@ -20,20 +25,19 @@ pub fn solve_part_1(input: &str) -> usize {
             let empty_cols = map.empty_columns.iter().filter(|&&col| min_x < col && col < max_x).count();
             let empty_rows = map.empty_rows.iter().filter(|&&row| min_y < row && row < max_y).count();
 
-            distance + empty_cols + empty_rows
+            (distance + empty_cols + empty_rows) as u64
         })
         .sum()
 }
 
-pub fn solve_part_2(input: &str, age_factor: usize) -> usize {
-    let map: GalaxyMap = input.parse().unwrap();
-
+fn part_2(map: &GalaxyMap, age_factor: u64) -> u64 {
     let add_cols_rows = age_factor - 1;
     map.galaxies
-        .into_iter()
+        .iter()
+        .copied()
         .tuple_combinations()
         .map(|(galaxy1, galaxy2)| {
-            let distance = galaxy1.distance(galaxy2);
+            let distance = galaxy1.manhattan_distance(galaxy2);
 
             let min_x = galaxy1.x.min(galaxy2.x);
             let max_x = galaxy1.x.max(galaxy2.x);
@@ -43,7 +47,33 @@ pub fn solve_part_2(input: &str, age_factor: usize) -> usize {
             let empty_cols = map.empty_columns.iter().filter(|&&col| min_x < col && col < max_x).count();
             let empty_rows = map.empty_rows.iter().filter(|&&row| min_y < row && row < max_y).count();
 
-            distance + empty_cols * add_cols_rows + empty_rows * add_cols_rows
+            distance as u64 + (empty_cols + empty_rows) as u64 * add_cols_rows
         })
         .sum()
 }
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    Ok(part_1(&input.parse()?))
+}
+
+pub fn solve_part_2(input: &str, age_factor: u64) -> Result<u64, SolveError> {
+    Ok(part_2(&input.parse()?, age_factor))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = GalaxyMap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(map: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_1(map).into())
+    }
+
+    fn part_2(map: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_2(map, 1000000).into())
+    }
+}
@@ -0,0 +1,96 @@
+//! Galaxy distances measured in the expanded image itself, or by walking across every row.
+
+fn image(input: &str) -> Vec<Vec<bool>> {
+    input
+        .lines()
+        .map(|line| line.chars().map(|char| char == '#').collect())
+        .collect()
+}
+
+fn galaxies(image: &[Vec<bool>]) -> Vec<(usize, usize)> {
+    (0..image.len())
+        .flat_map(|y| (0..image[y].len()).map(move |x| (x, y)))
+        .filter(|&(x, y)| image[y][x])
+        .collect()
+}
+
+fn transpose(image: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..image[0].len())
+        .map(|x| image.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+/// Duplicates every row without galaxies.
+fn expand_rows(image: Vec<Vec<bool>>) -> Vec<Vec<bool>> {
+    image
+        .into_iter()
+        .flat_map(|row| {
+            let copies = if row.contains(&true) { 1 } else { 2 };
+            std::iter::repeat_n(row, copies)
+        })
+        .collect()
+}
+
+/// Expands the image itself and measures distances in it.
+pub fn part_1(input: &str) -> u64 {
+    let image = transpose(&expand_rows(transpose(&expand_rows(image(input)))));
+    let galaxies = galaxies(&image);
+
+    galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(index, a)| galaxies[index + 1..].iter().map(move |b| (a, b)))
+        .map(|(a, b)| (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as u64)
+        .sum()
+}
+
+/// Walks between every pair of galaxies, one row and one column at a time.
+pub fn part_2(input: &str, age_factor: u64) -> u64 {
+    let image = image(input);
+    let row_widths = image
+        .iter()
+        .map(|row| if row.contains(&true) { 1 } else { age_factor })
+        .collect::<Vec<_>>();
+    let column_widths = transpose(&image)
+        .iter()
+        .map(|column| {
+            if column.contains(&true) {
+                1
+            } else {
+                age_factor
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let galaxies = galaxies(&image);
+    let walk = |widths: &[u64], a: usize, b: usize| -> u64 {
+        (a.min(b)..a.max(b)).map(|step| widths[step + 1]).sum()
+    };
+
+    galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(index, a)| galaxies[index + 1..].iter().map(move |b| (a, b)))
+        .map(|(a, b)| walk(&column_widths, a.0, b.0) + walk(&row_widths, a.1, b.1))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 2usize..20, age_factor in 1u64..1_000_000) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(part_2(&input, 2), part_1(&input));
+            prop_assert_eq!(solve_part_2(&input, age_factor), Ok(part_2(&input, age_factor)));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use aoc_gen::Rng;
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 1000;
+
+/// Generates `size` condition records of up to 20 springs, each with some damaged springs.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let len = rng.gen_range(1..=20);
+            let mut springs = (0..len)
+                .map(|_| if rng.gen_bool(0.4) { '#' } else { '.' })
+                .collect::<Vec<_>>();
+            if !springs.contains(&'#') {
+                springs[rng.gen_range(0..len)] = '#';
+            }
+
+            let groups = springs
+                .split(|&spring| spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect::<Vec<_>>();
+
+            let record = springs
+                .iter()
+                .map(|&spring| if rng.gen_bool(0.5) { '?' } else { spring })
+                .collect::<String>();
+
+            format!("{record} {}", groups.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
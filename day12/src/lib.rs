@@ -1,9 +1,16 @@
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+pub use generate::{generate, INPUT_SIZE};
 
 use std::{fmt::Display, iter};
 
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 use itertools::Itertools;
 use parser::parse_input;
+#[cfg(feature = "parallel")]
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,7 +48,7 @@ impl Display for Spring {
 }
 
 #[derive(Debug)]
-struct Record {
+pub struct Record {
     springs: Vec<Spring>,
     groups: Vec<usize>,
 }
@@ -65,7 +72,7 @@ fn count_valid_arrangements(
     springs: &[Spring],
     groups: &[usize],
     mut left_in_group: Option<usize>,
-) -> usize {
+) -> u64 {
     if springs.is_empty() {
         if groups.is_empty() && left_in_group.map(|x| x == 0).unwrap_or(true) {
             return 1;
@@ -148,38 +155,63 @@ fn count_valid_arrangements(
     }
 }
 
-pub fn solve_part_1(input: &str) -> usize {
-    let records = parse_input(input);
-
+fn part_1(records: &[Record]) -> u64 {
     records
-        .into_iter()
+        .iter()
         .map(|record| count_valid_arrangements(&record.springs, &record.groups, None))
         .sum()
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let records = parse_input(input)
-        .into_iter()
+fn part_2(records: &[Record]) -> u64 {
+    let records = records
+        .iter()
         .map(|record| {
-            let springs = iter::repeat(iter::once(Spring::Unknown).chain(record.springs))
-                .take(5)
+            let springs = iter::repeat_n(iter::once(Spring::Unknown).chain(record.springs.iter().copied()), 5)
                 .flatten()
                 .skip(1)
                 .collect();
-            let groups = iter::repeat(record.groups).take(5).flatten().collect();
+            let groups = iter::repeat_n(record.groups.iter().copied(), 5).flatten().collect();
 
             Record { springs, groups }
         })
         .collect_vec();
 
+    #[cfg(feature = "parallel")]
+    let records = records.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let records = records.into_iter();
+
     records
-        .into_par_iter()
         .map(|record| {
-            dbg!(count_valid_arrangements(
-                &record.springs,
-                &record.groups,
-                None
-            ))
+            let count = count_valid_arrangements(&record.springs, &record.groups, None);
+            log::trace!("{record:?} has {count} arrangements");
+            count
         })
         .sum()
 }
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    Ok(part_1(&parse_input(input)?))
+}
+
+pub fn solve_part_2(input: &str) -> Result<u64, SolveError> {
+    Ok(part_2(&parse_input(input)?))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(records: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_1(records).into())
+    }
+
+    fn part_2(records: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_2(records).into())
+    }
+}
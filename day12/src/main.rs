@@ -1,44 +1,56 @@
-use std::fs;
+use std::{env, process::ExitCode};
 
+use aoc_core::{input, Solution};
 use day12::*;
 
-fn main() {
-    let contents = fs::read_to_string("day12/input.txt").expect("Failed to read puzzle input");
+fn main() -> ExitCode {
+    let contents = input::load(env!("CARGO_MANIFEST_DIR"), env::args_os().nth(1))
+        .expect("Failed to read puzzle input");
+    let input = match Day12::parse(&contents) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match Day12::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Part 1: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day12::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Part 2: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    ExitCode::SUCCESS
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
+    const INPUT: &str = include_str!("../example.txt");
 
     #[test]
     fn test_part_1() {
         let sol = solve_part_1(INPUT);
 
-        assert_eq!(sol, 21);
+        assert_eq!(sol, Ok(21));
     }
 
     #[test]
     fn test_part_2() {
         let sol = solve_part_2(INPUT);
 
-        assert_eq!(sol, 525152);
+        assert_eq!(sol, Ok(525152));
     }
 
     #[test]
     fn test() {
         let sol = solve_part_1(".??.?.?#?##?#???#?? 1,11");
 
-        assert_eq!(sol, 6);
+        assert_eq!(sol, Ok(6));
     }
 }
@@ -1,25 +1,31 @@
 #![allow(clippy::type_complexity)]
 
+use std::num::NonZeroUsize;
+
+use aoc_core::{
+    parse::{lines, parse_all},
+    ParseError,
+};
 use nom::{
-    character::complete::{char, digit1, line_ending, one_of, space1},
-    combinator::{map, map_res},
+    character::complete::{char, digit1, one_of, space1},
+    combinator::{cut, map, map_res},
     multi::{many1, separated_list1},
     sequence::separated_pair,
-    Finish, IResult,
+    IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Vec<Record> {
-    let (_, records) = records_list(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    records
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Record>, ParseError> {
+    parse_all(input, records_list)
 }
 
-fn number(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, str::parse)(input)
+/// Size of a group of damaged springs, which cannot be empty.
+fn group_size(input: &str) -> IResult<&str, usize> {
+    map(
+        map_res(digit1, str::parse::<NonZeroUsize>),
+        NonZeroUsize::get,
+    )(input)
 }
 
 fn spring(input: &str) -> IResult<&str, Spring> {
@@ -31,7 +37,7 @@ fn springs_record(input: &str) -> IResult<&str, Vec<Spring>> {
 }
 
 fn groups_record(input: &str) -> IResult<&str, Vec<usize>> {
-    separated_list1(char(','), number)(input)
+    separated_list1(char(','), cut(group_size))(input)
 }
 
 fn record(input: &str) -> IResult<&str, Record> {
@@ -42,7 +48,7 @@ fn record(input: &str) -> IResult<&str, Record> {
 }
 
 fn records_list(input: &str) -> IResult<&str, Vec<Record>> {
-    separated_list1(line_ending, record)(input)
+    lines(record)(input)
 }
 
 #[cfg(test)]
@@ -100,4 +106,13 @@ mod tests {
         assert_eq!(record.groups, vec![1, 1, 2]);
         assert!(rem.is_empty());
     }
+
+    #[test]
+    fn report_empty_groups() {
+        let error = parse_input("?.# 0,1").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 5));
+
+        let error = parse_input("#.? 1\n?.# 1,0").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 7));
+    }
 }
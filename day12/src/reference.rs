@@ -0,0 +1,137 @@
+//! Spring arrangements from trying every combination of unknown springs, or from counting
+//! them spring by spring once unfolded.
+
+use std::collections::HashMap;
+
+fn records(input: &str) -> Vec<(Vec<u8>, Vec<usize>)> {
+    input
+        .lines()
+        .map(|line| {
+            let (springs, groups) = line.split_once(' ').unwrap();
+            let groups = groups
+                .split(',')
+                .map(|group| group.parse().unwrap())
+                .collect();
+
+            (springs.as_bytes().to_vec(), groups)
+        })
+        .collect()
+}
+
+/// Sizes of the groups of damaged springs.
+fn groups(springs: &[u8]) -> Vec<usize> {
+    springs
+        .split(|&spring| spring == b'.')
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len())
+        .collect()
+}
+
+/// Tries every combination of damaged and operational springs for the unknown ones.
+fn count_by_enumeration(springs: &[u8], expected: &[usize]) -> u64 {
+    let unknown = springs.iter().filter(|&&spring| spring == b'?').count();
+
+    (0..1u32 << unknown)
+        .filter(|combination| {
+            let mut bit = 0;
+            let springs = springs
+                .iter()
+                .map(|&spring| {
+                    if spring != b'?' {
+                        return spring;
+                    }
+                    bit += 1;
+                    if combination & (1 << (bit - 1)) != 0 {
+                        b'#'
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            groups(&springs) == expected
+        })
+        .count() as u64
+}
+
+/// Counts arrangements of the springs from `index` on, with `run` damaged springs right
+/// before it and the groups before `group` complete.
+fn count_by_recurrence(
+    springs: &[u8],
+    groups: &[usize],
+    (index, group, run): (usize, usize, usize),
+    memo: &mut HashMap<(usize, usize, usize), u64>,
+) -> u64 {
+    if index == springs.len() {
+        let complete = match run {
+            0 => group == groups.len(),
+            _ => group + 1 == groups.len() && groups[group] == run,
+        };
+        return complete as u64;
+    }
+    if let Some(&count) = memo.get(&(index, group, run)) {
+        return count;
+    }
+
+    let mut count = 0;
+    if springs[index] != b'.' && group < groups.len() && run < groups[group] {
+        count += count_by_recurrence(springs, groups, (index + 1, group, run + 1), memo);
+    }
+    if springs[index] != b'#' {
+        if run == 0 {
+            count += count_by_recurrence(springs, groups, (index + 1, group, 0), memo);
+        } else if groups[group] == run {
+            count += count_by_recurrence(springs, groups, (index + 1, group + 1, 0), memo);
+        }
+    }
+
+    memo.insert((index, group, run), count);
+    count
+}
+
+pub fn part_1(input: &str) -> u64 {
+    records(input)
+        .iter()
+        .map(|(springs, groups)| count_by_enumeration(springs, groups))
+        .sum()
+}
+
+/// Unfolded records have too many unknown springs to try them all, so arrangements are
+/// counted spring by spring instead.
+pub fn part_2(input: &str) -> u64 {
+    records(input)
+        .iter()
+        .map(|(springs, groups)| {
+            let springs = [&springs[..]; 5].join(&b'?');
+            let groups = groups.repeat(5);
+
+            count_by_recurrence(&springs, &groups, (0, 0, 0), &mut HashMap::new())
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..20) {
+            let input = generate(size, &mut rng(seed));
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+
+            // The optimised solution takes too long on long unfolded records.
+            let short = input.lines().filter(|line| line.find(' ').unwrap() <= 6).collect::<Vec<_>>();
+            if !short.is_empty() {
+                let short = short.join("\n");
+                prop_assert_eq!(solve_part_2(&short), Ok(part_2(&short)));
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_gen::Rng;
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 100;
+
+/// Generates `size` patterns, each with a single line of reflection and a single line that
+/// becomes one once a smudge is fixed.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| loop {
+            let pattern = pattern(rng);
+            if has_unique_reflections(&pattern) {
+                break pattern
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|&rock| if rock { '#' } else { '.' })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Random pattern reflected across a horizontal line, and across a vertical line but for one
+/// smudge, possibly transposed.
+///
+/// The smudge is put in a row that the horizontal reflection leaves out, so that it does not
+/// break that one.
+fn pattern(rng: &mut impl Rng) -> Vec<Vec<bool>> {
+    let (width, height) = (rng.gen_range(5..=17), rng.gen_range(5..=17));
+    let mut pattern = (0..height)
+        .map(|_| (0..width).map(|_| rng.gen_bool(0.5)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let column = rng.gen_range(1..width);
+    for row in &mut pattern {
+        for offset in 0..column.min(width - column) {
+            row[column + offset] = row[column - 1 - offset];
+        }
+    }
+
+    let line = loop {
+        let line = rng.gen_range(1..height);
+        if 2 * line != height {
+            break line;
+        }
+    };
+    let reflected = line.min(height - line);
+    for offset in 0..reflected {
+        pattern[line + offset] = pattern[line - 1 - offset].clone();
+    }
+
+    let row = if line < height - line {
+        rng.gen_range(2 * line..height)
+    } else {
+        rng.gen_range(0..height - 2 * reflected)
+    };
+    let smudge =
+        rng.gen_range(column - column.min(width - column)..column + column.min(width - column));
+    pattern[row][smudge] = !pattern[row][smudge];
+
+    if rng.gen_bool(0.5) {
+        transpose(&pattern)
+    } else {
+        pattern
+    }
+}
+
+fn transpose(pattern: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    (0..pattern[0].len())
+        .map(|x| pattern.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+/// Whether exactly one line reflects the pattern perfectly and exactly one reflects it but
+/// for one tile, with no other line reflecting all rows but one.
+fn has_unique_reflections(pattern: &[Vec<bool>]) -> bool {
+    let lines = [pattern.to_vec(), transpose(pattern)]
+        .iter()
+        .flat_map(|pattern| {
+            (1..pattern.len())
+                .map(|line| {
+                    // Mismatched tiles of each column across the line.
+                    let mismatches = (0..pattern[0].len())
+                        .map(|x| {
+                            (0..line.min(pattern.len() - line))
+                                .filter(|&offset| {
+                                    pattern[line - 1 - offset][x] != pattern[line + offset][x]
+                                })
+                                .count()
+                        })
+                        .collect::<Vec<_>>();
+                    (
+                        mismatches.iter().sum::<usize>(),
+                        mismatches.iter().filter(|&&count| count > 0).count(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    lines.iter().filter(|&&(total, _)| total == 0).count() == 1
+        && lines.iter().filter(|&&(total, _)| total == 1).count() == 1
+        && !lines
+            .iter()
+            .any(|&(total, columns)| columns == 1 && total > 1)
+}
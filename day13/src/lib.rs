@@ -1,10 +1,15 @@
+mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+pub use generate::{generate, INPUT_SIZE};
+
 use std::{collections::BTreeSet, str::FromStr};
 
-use grid::Grid;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_grid::Grid;
 use itertools::Itertools;
 
-pub mod grid;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Ash,
@@ -23,30 +28,24 @@ impl TryFrom<char> for Tile {
     }
 }
 
-struct Map {
+pub struct Map {
     patterns: Vec<Grid<Tile>>,
 }
 
 impl FromStr for Map {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let patterns = input
-            .replace("\r\n", "\n")
-            .split("\n\n")
-            .map(|pattern_str| {
-                let lines = pattern_str.lines().collect_vec();
-                let height = lines.len();
-                let width = lines[0].len();
-                let tiles: Vec<_> = lines
-                    .into_iter()
-                    .flat_map(|line| line.chars())
-                    .map(TryFrom::try_from)
-                    .collect::<Result<_, _>>()?;
-
-                Ok(Grid::new(width, height, tiles))
-            })
-            .collect::<Result<_, _>>()?;
+        let mut patterns = Vec::new();
+        let mut line_offset = 0;
+        for pattern_str in input.split("\n\n") {
+            let pattern = pattern_str
+                .parse()
+                .map_err(|error: ParseError| error.with_line_offset(line_offset))?;
+            patterns.push(pattern);
+
+            line_offset += pattern_str.lines().count() + 1;
+        }
 
         Ok(Self { patterns })
     }
@@ -55,7 +54,7 @@ impl FromStr for Map {
 fn find_vertical_reflection_points(grid: &Grid<Tile>) -> BTreeSet<usize> {
     (0..grid.height)
         .map(|y| {
-            (0..(grid.width - 1))
+            (0..grid.width.saturating_sub(1))
                 .filter(|&x| {
                     let left = (0..=x).rev();
                     let right = (x + 1)..grid.width;
@@ -66,13 +65,13 @@ fn find_vertical_reflection_points(grid: &Grid<Tile>) -> BTreeSet<usize> {
                 .collect::<BTreeSet<_>>()
         })
         .tree_fold1(|a, b| a.intersection(&b).cloned().collect())
-        .expect("grid is not empty")
+        .unwrap_or_default()
 }
 
 fn find_horizontal_reflection_points(grid: &Grid<Tile>) -> BTreeSet<usize> {
     (0..grid.width)
         .map(|x| {
-            (0..(grid.height - 1))
+            (0..grid.height.saturating_sub(1))
                 .filter(|&y| {
                     let up = (0..=y).rev();
                     let down = (y + 1)..grid.height;
@@ -82,29 +81,38 @@ fn find_horizontal_reflection_points(grid: &Grid<Tile>) -> BTreeSet<usize> {
                 .collect::<BTreeSet<_>>()
         })
         .tree_fold1(|a, b| a.intersection(&b).cloned().collect())
-        .expect("grid is not empty")
+        .unwrap_or_default()
 }
 
-fn find_reflection(grid: Grid<Tile>) -> usize {
-    if let Some(x) = find_vertical_reflection_points(&grid).into_iter().next() {
-        x + 1
-    } else if let Some(x) = find_horizontal_reflection_points(&grid).into_iter().next() {
-        100 * (x + 1)
+fn find_reflection(grid: &Grid<Tile>) -> Option<usize> {
+    if let Some(x) = find_vertical_reflection_points(grid).into_iter().next() {
+        Some(x + 1)
     } else {
-        panic!("Pattern contains no reflections!")
+        find_horizontal_reflection_points(grid).into_iter().next().map(|x| 100 * (x + 1))
     }
 }
 
-pub fn solve_part_1(input: &str) -> usize {
-    let map: Map = input.parse().unwrap();
+/// Sums the notes of all patterns, summarized by `find`.
+fn summarize(map: &Map, find: fn(&Grid<Tile>) -> Option<usize>) -> Result<usize, SolveError> {
+    map.patterns
+        .iter()
+        .enumerate()
+        .map(|(idx, pattern)| {
+            find(pattern).ok_or_else(|| {
+                SolveError::not_found(format!("line of reflection in pattern {}", idx + 1))
+            })
+        })
+        .sum()
+}
 
-    map.patterns.into_iter().map(find_reflection).sum()
+fn part_1(map: &Map) -> Result<usize, SolveError> {
+    summarize(map, find_reflection)
 }
 
 fn find_vertical_reflection_points_with_smudge(grid: &Grid<Tile>) -> BTreeSet<usize> {
     (0..grid.height)
         .flat_map(|y: usize| {
-            (0..(grid.width - 1)).filter(move |&x| {
+            (0..grid.width.saturating_sub(1)).filter(move |&x| {
                 let left = (0..=x).rev();
                 let right = (x + 1)..grid.width;
 
@@ -121,7 +129,7 @@ fn find_vertical_reflection_points_with_smudge(grid: &Grid<Tile>) -> BTreeSet<us
 fn find_horizontal_reflection_points_with_smudge(grid: &Grid<Tile>) -> BTreeSet<usize> {
     (0..grid.width)
         .flat_map(|x| {
-            (0..(grid.height - 1)).filter(move |&y| {
+            (0..grid.height.saturating_sub(1)).filter(move |&y| {
                 let up = (0..=y).rev();
                 let down = (y + 1)..grid.height;
 
@@ -134,20 +142,45 @@ fn find_horizontal_reflection_points_with_smudge(grid: &Grid<Tile>) -> BTreeSet<
         .collect()
 }
 
-fn find_reflection_with_smudge(grid: Grid<Tile>) -> usize {
-    if let Some(x) = find_vertical_reflection_points_with_smudge(&grid).into_iter().next() {
-        x + 1
-    } else if let Some(x) = find_horizontal_reflection_points_with_smudge(&grid).into_iter().next() {
-        100 * (x + 1)
+fn find_reflection_with_smudge(grid: &Grid<Tile>) -> Option<usize> {
+    if let Some(x) = find_vertical_reflection_points_with_smudge(grid).into_iter().next() {
+        Some(x + 1)
     } else {
-        panic!("Pattern contains no reflections!")
+        find_horizontal_reflection_points_with_smudge(grid)
+            .into_iter()
+            .next()
+            .map(|x| 100 * (x + 1))
     }
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let map: Map = input.parse().unwrap();
+fn part_2(map: &Map) -> Result<usize, SolveError> {
+    summarize(map, find_reflection_with_smudge)
+}
+
+pub fn solve_part_1(input: &str) -> Result<usize, SolveError> {
+    part_1(&input.parse()?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<usize, SolveError> {
+    part_2(&input.parse()?)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
 
-    map.patterns.into_iter().map(find_reflection_with_smudge).sum()
+    fn part_1(map: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(map).map(Answer::from)
+    }
+
+    fn part_2(map: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(map).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -171,6 +204,20 @@ mod tests {
         assert_eq!(map.patterns[0].get(3, 2), Some(&Tile::Rock));
     }
 
+    #[test]
+    fn report_error_in_later_pattern() {
+        let input = ".##.
+#..#
+
+#.#
+.x.";
+
+        let error = input.parse::<Map>().err().unwrap();
+
+        assert_eq!((error.line(), error.column()), (5, 2));
+        assert_eq!(error.snippet(), ".x.");
+    }
+
     #[test]
     fn find_reflection_across_vertical() {
         let input = "#.##..##.
@@ -178,7 +225,7 @@ mod tests {
 
         let mut map: Map = input.parse().unwrap();
 
-        assert_eq!(find_reflection(map.patterns.pop().unwrap()), 5);
+        assert_eq!(find_reflection(&map.patterns.pop().unwrap()), Some(5));
     }
 
     #[test]
@@ -193,7 +240,7 @@ mod tests {
 
         let mut map: Map = input.parse().unwrap();
 
-        assert_eq!(find_reflection(map.patterns.pop().unwrap()), 400);
+        assert_eq!(find_reflection(&map.patterns.pop().unwrap()), Some(400));
     }
 
     #[test]
@@ -209,8 +256,36 @@ mod tests {
         let mut map: Map = input.parse().unwrap();
 
         assert_eq!(
-            find_reflection_with_smudge(map.patterns.pop().unwrap()),
-            100
+            find_reflection_with_smudge(&map.patterns.pop().unwrap()),
+            Some(100)
+        );
+    }
+
+    #[test]
+    fn report_empty_pattern() {
+        let map = Map {
+            patterns: vec![Grid::new(0, 0, Vec::new())],
+        };
+
+        assert_eq!(
+            part_2(&map),
+            Err(SolveError::not_found("line of reflection in pattern 1"))
+        );
+    }
+
+    #[test]
+    fn report_pattern_without_reflection() {
+        let input = "#.##..##.
+..#.##.#.
+
+#.
+..";
+
+        let map: Map = input.parse().unwrap();
+
+        assert_eq!(
+            part_1(&map),
+            Err(SolveError::not_found("line of reflection in pattern 2"))
         );
     }
 }
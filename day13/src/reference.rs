@@ -0,0 +1,68 @@
+//! Lines of reflection from counting the tiles that do not match their mirror image.
+
+/// Tiles that differ from their mirror image across the line after `line` columns.
+fn mismatches(pattern: &[Vec<u8>], line: usize) -> usize {
+    let width = pattern[0].len();
+
+    pattern
+        .iter()
+        .map(|row| {
+            (0..line.min(width - line))
+                .filter(|&offset| row[line - 1 - offset] != row[line + offset])
+                .count()
+        })
+        .sum()
+}
+
+fn transpose(pattern: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    (0..pattern[0].len())
+        .map(|x| pattern.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+/// Summarizes the lines of reflection with exactly `smudges` tiles that do not match.
+fn summarize(input: &str, smudges: usize) -> usize {
+    input
+        .split("\n\n")
+        .map(|pattern| {
+            let pattern = pattern
+                .lines()
+                .map(|line| line.as_bytes().to_vec())
+                .collect::<Vec<_>>();
+            let line = |pattern: &[Vec<u8>]| {
+                (1..pattern[0].len()).find(|&line| mismatches(pattern, line) == smudges)
+            };
+
+            line(&pattern)
+                .or_else(|| line(&transpose(&pattern)).map(|line| 100 * line))
+                .unwrap()
+        })
+        .sum()
+}
+
+pub fn part_1(input: &str) -> usize {
+    summarize(input, 0)
+}
+
+pub fn part_2(input: &str) -> usize {
+    summarize(input, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..10) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use aoc_gen::{grid, weighted, Rng};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 100;
+
+/// Generates a `size` by `size` platform of rounded and cube-shaped rocks.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+
+    grid(size, size, rng, |rng| {
+        weighted(rng, &[('.', 13), ('O', 4), ('#', 3)])
+    })
+}
@@ -1,27 +1,21 @@
-use std::collections::BTreeSet;
+use std::iter;
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_cycle::{state_after_n_steps, Algorithm};
+use aoc_viz::{Animation, Cell, Color, Frame, Palette, Style};
 
 use crate::reflector_dish::{ReflectorDish, Tile};
 
-mod grid;
+mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 mod reflector_dish;
 
-pub fn solve_part_1(input: &str) -> u64 {
-    let mut dish: ReflectorDish = input.parse().unwrap();
-
-    for (x, y) in dish.grid.iter_indices() {
-        if dish.grid.get(x, y) != Some(&Tile::RoundedRock) {
-            continue;
-        }
+pub use generate::{generate, INPUT_SIZE};
 
-        if let Some(j) = (0..y)
-            .rev()
-            .take_while(|&j| dish.grid.get(x, j) == Some(&Tile::Empty))
-            .last()
-        {
-            *dish.grid.get_mut(x, y).unwrap() = Tile::Empty;
-            *dish.grid.get_mut(x, j).unwrap() = Tile::RoundedRock;
-        }
-    }
+fn part_1(dish: &ReflectorDish) -> u64 {
+    let mut dish = dish.clone();
+    tilt_dish_north(&mut dish);
 
     dish.north_load()
 }
@@ -96,40 +90,79 @@ fn tilt_dish_east(dish: &mut ReflectorDish) {
     }
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let mut dish: ReflectorDish = input.parse().unwrap();
-
-    let hash_state = ahash::RandomState::new();
-    let mut visited = BTreeSet::new();
-    let mut cycle = Vec::new();
-
-    let dish_hash = hash_state.hash_one(&dish);
-    visited.insert(dish_hash);
-    cycle.push((dish_hash, dish.north_load()));
-
-    const NUM_CYCLES: usize = 1000000000;
-    for i in 0..NUM_CYCLES {
-        tilt_dish_north(&mut dish);
-        tilt_dish_west(&mut dish);
-        tilt_dish_south(&mut dish);
-        tilt_dish_east(&mut dish);
-
-        let dish_hash = hash_state.hash_one(&dish);
-        if !visited.insert(dish_hash) {
-            // Found cycle
-
-            let start_idx = cycle
-                .iter()
-                .position(|&(hash, _)| hash == dish_hash)
-                .expect("cycle exists");
-            let end_idx = i + 1;
-            let cycle_len = end_idx - start_idx;
-            let idx = (NUM_CYCLES - start_idx) % cycle_len;
-            return cycle[start_idx + idx].1;
-        }
+fn spin_cycle(dish: &ReflectorDish) -> ReflectorDish {
+    let mut dish = dish.clone();
+
+    tilt_dish_north(&mut dish);
+    tilt_dish_west(&mut dish);
+    tilt_dish_south(&mut dish);
+    tilt_dish_east(&mut dish);
+
+    dish
+}
+
+const NUM_CYCLES: usize = 1000000000;
 
-        cycle.push((dish_hash, dish.north_load()));
+fn part_2(dish: &ReflectorDish) -> u64 {
+    state_after_n_steps(dish.clone(), spin_cycle, NUM_CYCLES, Algorithm::Brent).north_load()
+}
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    Ok(part_1(&input.parse()?))
+}
+
+pub fn solve_part_2(input: &str) -> Result<u64, SolveError> {
+    Ok(part_2(&input.parse()?))
+}
+
+const PALETTE: Palette = Palette {
+    tile: Color::rgb(70, 70, 80),
+    accent: Color::rgb(160, 160, 170),
+    highlight: Color::ORANGE,
+    ..Palette::DEFAULT
+};
+
+fn draw(dish: &ReflectorDish) -> Frame {
+    Frame::new(&dish.grid, |&tile| match tile {
+        Tile::Empty => Cell::new('.', Style::fg(PALETTE.tile)),
+        Tile::RoundedRock => Cell::new('O', Style::fg(PALETTE.highlight).bold()),
+        Tile::CubeRock => Cell::new('#', Style::fg(PALETTE.accent)),
+    })
+}
+
+/// Shows the dish before and after tilting it north.
+pub fn visualize_part_1(input: &str) -> Result<Animation, SolveError> {
+    let dish: ReflectorDish = input.parse()?;
+    let mut tilted = dish.clone();
+    tilt_dish_north(&mut tilted);
+
+    Ok(Animation::from_iter([draw(&dish), draw(&tilted)]).with_palette(PALETTE))
+}
+
+/// Animates the spin cycles until the dish is in the state it ends up in after all of them.
+pub fn visualize_part_2(input: &str) -> Result<Animation, SolveError> {
+    let dish: ReflectorDish = input.parse()?;
+    let last = state_after_n_steps(dish.clone(), spin_cycle, NUM_CYCLES, Algorithm::Brent);
+
+    let dishes = iter::successors(Some(dish), |dish| (*dish != last).then(|| spin_cycle(dish)));
+
+    Ok(dishes.map(|dish| draw(&dish)).collect::<Animation>().with_palette(PALETTE))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = ReflectorDish;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
     }
 
-    panic!("Did not found any cycle!")
+    fn part_1(dish: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_1(dish).into())
+    }
+
+    fn part_2(dish: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_2(dish).into())
+    }
 }
@@ -1,41 +1,49 @@
-use std::fs;
+use std::{env, process::ExitCode};
 
+use aoc_core::{input, Solution};
 use day14::*;
 
-fn main() {
-    let contents = fs::read_to_string("day14/input.txt").expect("Failed to read puzzle input");
+fn main() -> ExitCode {
+    let contents = input::load(env!("CARGO_MANIFEST_DIR"), env::args_os().nth(1))
+        .expect("Failed to read puzzle input");
+    let input = match Day14::parse(&contents) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match Day14::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Part 1: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day14::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Part 2: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    ExitCode::SUCCESS
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
+    const INPUT: &str = include_str!("../example.txt");
 
     #[test]
     fn test_part_1() {
         let sol = solve_part_1(INPUT);
 
-        assert_eq!(sol, 136);
+        assert_eq!(sol, Ok(136));
     }
 
     #[test]
     fn test_part_2() {
         let sol = solve_part_2(INPUT);
 
-        assert_eq!(sol, 64);
+        assert_eq!(sol, Ok(64));
     }
 }
@@ -0,0 +1,92 @@
+//! Rock loads from rolling rocks a tile at a time and keeping every platform seen while
+//! spinning.
+
+type Platform = Vec<Vec<u8>>;
+
+/// Moves rounded rocks one tile at a time towards the north until none can move.
+fn tilt_north(platform: &mut Platform) {
+    let mut moved = true;
+    while moved {
+        moved = false;
+        for y in 1..platform.len() {
+            for x in 0..platform[y].len() {
+                if platform[y][x] == b'O' && platform[y - 1][x] == b'.' {
+                    platform[y][x] = b'.';
+                    platform[y - 1][x] = b'O';
+                    moved = true;
+                }
+            }
+        }
+    }
+}
+
+/// Turns the platform clockwise, so that its west side faces north.
+fn rotate(platform: &Platform) -> Platform {
+    let height = platform.len();
+    (0..platform[0].len())
+        .map(|x| (0..height).rev().map(|y| platform[y][x]).collect())
+        .collect()
+}
+
+fn spin_cycle(platform: &Platform) -> Platform {
+    (0..4).fold(platform.clone(), |mut platform, _| {
+        tilt_north(&mut platform);
+        rotate(&platform)
+    })
+}
+
+fn north_load(platform: &Platform) -> u64 {
+    platform
+        .iter()
+        .enumerate()
+        .map(|(y, row)| {
+            ((platform.len() - y) * row.iter().filter(|&&tile| tile == b'O').count()) as u64
+        })
+        .sum()
+}
+
+fn platform(input: &str) -> Platform {
+    input.lines().map(|line| line.as_bytes().to_vec()).collect()
+}
+
+pub fn part_1(input: &str) -> u64 {
+    let mut platform = platform(input);
+    tilt_north(&mut platform);
+
+    north_load(&platform)
+}
+
+/// Spins until a platform repeats one seen before, keeping all of them, then picks the one
+/// the remaining spins end on.
+pub fn part_2(input: &str) -> u64 {
+    const SPINS: usize = 1_000_000_000;
+
+    let mut seen = vec![platform(input)];
+    loop {
+        let next = spin_cycle(seen.last().unwrap());
+        if let Some(start) = seen.iter().position(|platform| platform == &next) {
+            let index = start + (SPINS - start) % (seen.len() - start);
+            return north_load(&seen[index]);
+        }
+        seen.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..12) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
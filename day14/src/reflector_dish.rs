@@ -1,11 +1,7 @@
-use std::{
-    fmt::Display,
-    str::FromStr,
-};
+use std::{fmt::Display, str::FromStr};
 
-use itertools::Itertools;
-
-use crate::grid::Grid;
+use aoc_core::ParseError;
+use aoc_grid::Grid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Tile {
@@ -29,19 +25,17 @@ impl TryFrom<char> for Tile {
     }
 }
 
-impl Display for Tile {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let char = match self {
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
             Tile::Empty => '.',
             Tile::RoundedRock => 'O',
             Tile::CubeRock => '#',
-        };
-
-        write!(f, "{}", char)
+        }
     }
 }
 
-#[derive(Hash)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ReflectorDish {
     pub grid: Grid<Tile>,
 }
@@ -59,31 +53,17 @@ impl ReflectorDish {
 }
 
 impl FromStr for ReflectorDish {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let lines = input.lines().collect_vec();
-        let height = lines.len();
-        let width = lines[0].len();
-        let tiles = lines
-            .into_iter()
-            .flat_map(|line| line.chars())
-            .map(|char| char.try_into().unwrap());
+        let grid = input.parse()?;
 
-        Ok(Self {
-            grid: Grid::new(width, height, tiles),
-        })
+        Ok(Self { grid })
     }
 }
 
 impl Display for ReflectorDish {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for y in 0..self.grid.height {
-            for x in 0..self.grid.width {
-                write!(f, "{}", self.grid.get(x, y).unwrap())?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        write!(f, "{}", self.grid)
     }
 }
@@ -0,0 +1,31 @@
+use aoc_gen::Rng;
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 4000;
+
+/// Generates an initialization sequence of `size` steps.
+///
+/// Labels are drawn from a limited pool so that steps often replace or remove lenses that
+/// are already in place.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+    let labels = (0..size.div_ceil(3))
+        .map(|_| {
+            (0..rng.gen_range(2..=6))
+                .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    (0..size)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -1,5 +1,13 @@
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
+pub use generate::{generate, INPUT_SIZE};
+
+use std::fmt::Display;
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 use itertools::Itertools;
 use parser::parse_input;
 
@@ -33,21 +41,34 @@ impl Lens {
     }
 }
 
-#[derive(Debug)]
-enum Instruction<'a> {
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction<'a> {
     RemoveLens { label: &'a str },
     InsertLens { label: &'a str, focal_length: u8 },
 }
 
-pub fn solve_part_1(input: &str) -> u64 {
-    input.trim().split(',').map(|x| hash_string(x) as u64).sum()
+impl Display for Instruction<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::RemoveLens { label } => write!(f, "{label}-"),
+            Instruction::InsertLens {
+                label,
+                focal_length,
+            } => write!(f, "{label}={focal_length}"),
+        }
+    }
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let instructions = parse_input(input);
+fn part_1(instructions: &[Instruction]) -> u64 {
+    instructions
+        .iter()
+        .map(|instruction| hash_string(&instruction.to_string()) as u64)
+        .sum()
+}
 
+fn part_2(instructions: &[Instruction]) -> u64 {
     let mut lens_boxes = Vec::<Lens>::new();
-    for instruction in instructions {
+    for &instruction in instructions {
         match instruction {
             Instruction::InsertLens {
                 label,
@@ -79,3 +100,29 @@ pub fn solve_part_2(input: &str) -> u64 {
         })
         .sum()
 }
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    Ok(part_1(&parse_input(input)?))
+}
+
+pub fn solve_part_2(input: &str) -> Result<u64, SolveError> {
+    Ok(part_2(&parse_input(input)?))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Instruction<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(instructions: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_1(instructions).into())
+    }
+
+    fn part_2(instructions: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_2(instructions).into())
+    }
+}
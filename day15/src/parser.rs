@@ -1,20 +1,20 @@
+use aoc_core::{
+    parse::{parse_all},
+    ParseError,
+};
 use nom::{
     branch::alt,
     character::complete::{alpha1, char, digit1},
-    combinator::{map, map_res},
+    combinator::{cut, map, map_res},
     multi::separated_list1,
     sequence::{separated_pair, terminated},
-    Finish, IResult,
+    IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Vec<Instruction> {
-    let (_, instructions) = instruction_list(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    instructions
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Instruction<'_>>, ParseError> {
+    parse_all(input, instruction_list)
 }
 
 fn label(input: &str) -> IResult<&str, &str> {
@@ -40,8 +40,8 @@ fn instruction(input: &str) -> IResult<&str, Instruction<'_>> {
     ))(input)
 }
 
-fn instruction_list(input: &str) -> IResult<&str, Vec<Instruction>> {
-    separated_list1(char(','), instruction)(input)
+fn instruction_list(input: &str) -> IResult<&str, Vec<Instruction<'_>>> {
+    separated_list1(char(','), cut(instruction))(input)
 }
 
 #[cfg(test)]
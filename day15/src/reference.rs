@@ -0,0 +1,62 @@
+//! Hashes and focusing power with a plain list of lenses in each box.
+
+fn hash(string: &str) -> u64 {
+    string
+        .bytes()
+        .fold(0, |hash, byte| (hash + byte as u64) * 17 % 256)
+}
+
+pub fn part_1(input: &str) -> u64 {
+    input.split(',').map(hash).sum()
+}
+
+/// Keeps the lenses of each of the 256 boxes in a list of their own.
+pub fn part_2(input: &str) -> u64 {
+    let mut boxes: Vec<Vec<(&str, u64)>> = vec![Vec::new(); 256];
+    for step in input.split(',') {
+        if let Some(label) = step.strip_suffix('-') {
+            boxes[hash(label) as usize].retain(|&(other, _)| other != label);
+        } else {
+            let (label, focal_length) = step.split_once('=').unwrap();
+            let focal_length = focal_length.parse().unwrap();
+            let lenses = &mut boxes[hash(label) as usize];
+
+            match lenses.iter_mut().find(|(other, _)| *other == label) {
+                Some(lens) => lens.1 = focal_length,
+                None => lenses.push((label, focal_length)),
+            }
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(box_no, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(slot, &(_, focal_length))| {
+                    (box_no as u64 + 1) * (slot as u64 + 1) * focal_length
+                })
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..100) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use aoc_gen::{grid, weighted, Rng};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 110;
+
+/// Generates a `size` by `size` contraption of mirrors and splitters.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+
+    grid(size, size, rng, |rng| {
+        weighted(rng, &[('.', 36), ('/', 1), ('\\', 1), ('|', 1), ('-', 1)])
+    })
+}
@@ -1,35 +1,17 @@
-mod grid;
+mod generate;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
-use std::collections::{HashSet, VecDeque};
+pub use generate::{generate, INPUT_SIZE};
 
-use grid::Grid;
-use itertools::Itertools;
+use std::{collections::HashSet, iter};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Position {
-    x: usize,
-    y: usize,
-}
-
-impl Position {
-    fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-
-    fn move_in(self, direction: Direction) -> Option<Self> {
-        let position = match direction {
-            Direction::North => Self::new(self.x, self.y.checked_sub(1)?),
-            Direction::South => Self::new(self.x, self.y.checked_add(1)?),
-            Direction::West => Self::new(self.x.checked_sub(1)?, self.y),
-            Direction::East => Self::new(self.x.checked_add(1)?, self.y),
-        };
-
-        Some(position)
-    }
-}
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_viz::{Animation, Cell, Color, Frame, Overlay, Palette, Style};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Tile {
+pub enum Tile {
     Empty,
     RightSlopedMirror,
     LeftSlopedMirror,
@@ -66,20 +48,13 @@ impl From<Tile> for char {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-
-impl Direction {
-    fn interact(self, tile: Tile) -> (Self, Option<Self>) {
+impl Tile {
+    /// Directions in which a beam continues after entering the tile going in `direction`.
+    fn interact(self, direction: Direction) -> (Direction, Option<Direction>) {
         use Direction::*;
         use Tile::*;
-        match (tile, self) {
-            (Empty, _) => (self, None),
+        match (self, direction) {
+            (Empty, _) => (direction, None),
             (RightSlopedMirror, North) => (East, None),
             (RightSlopedMirror, South) => (West, None),
             (RightSlopedMirror, West) => (South, None),
@@ -88,88 +63,194 @@ impl Direction {
             (LeftSlopedMirror, South) => (East, None),
             (LeftSlopedMirror, West) => (North, None),
             (LeftSlopedMirror, East) => (South, None),
-            (VerticalSplitter, North | South) => (self, None),
+            (VerticalSplitter, North | South) => (direction, None),
             (VerticalSplitter, West | East) => (North, Some(South)),
             (HorizontalSplitter, North | South) => (West, Some(East)),
-            (HorizontalSplitter, West | East) => (self, None),
+            (HorizontalSplitter, West | East) => (direction, None),
         }
     }
 }
 
-fn parse_input(input: &str) -> Grid<Tile> {
-    let lines = input.lines().collect_vec();
-    let height = lines.len();
-    let width = lines[0].len();
-    let tiles: Vec<_> = lines
-        .into_iter()
-        .flat_map(|line| line.chars())
-        .map(Tile::try_from)
-        .collect::<Result<_, _>>()
-        .expect("Failed to parse puzzle input");
-
-    Grid::new(width, height, tiles)
+fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    input.parse()
 }
 
-fn count_energized(grid: &Grid<Tile>, start_pos: Position, start_dir: Direction) -> usize {
-    let mut queue = VecDeque::new();
-    queue.push_back((start_pos, start_dir));
-
-    let mut visited = HashSet::new();
-    let mut energized = HashSet::new();
-    while let Some((mut position, mut direction)) = queue.pop_front() {
-        while let Some(&tile) = grid.get(position.x, position.y) {
-            if !visited.insert((position, direction)) {
-                break;
-            }
+/// Follows the beam entering at `start_pos` going `start_dir` until it only revisits tiles,
+/// calling `on_step` with the heads of all beams before each step.
+fn energize(
+    grid: &Grid<Tile>,
+    start_pos: Pos,
+    start_dir: Direction,
+    mut on_step: impl FnMut(&HashSet<Pos>, &[(Pos, Direction)]),
+) -> HashSet<Pos> {
+    let mut heads = vec![(start_pos, start_dir)];
+    let mut visited = HashSet::from([(start_pos, start_dir)]);
+    let mut energized = HashSet::from([start_pos]);
 
-            energized.insert(position);
+    while !heads.is_empty() {
+        on_step(&energized, &heads);
 
-            let (dir, new_dir) = direction.interact(tile);
-            if let Some(new_dir) = new_dir {
-                if let Some(new_pos) = position.move_in(new_dir) {
-                    queue.push_back((new_pos, new_dir));
+        let mut next_heads = Vec::with_capacity(heads.len());
+        for (position, direction) in heads {
+            let (dir, new_dir) = grid[position].interact(direction);
+            for dir in iter::once(dir).chain(new_dir) {
+                let Some(pos) = grid.step(position, dir) else {
+                    continue;
+                };
+                if visited.insert((pos, dir)) {
+                    energized.insert(pos);
+                    next_heads.push((pos, dir));
                 }
             }
-
-            direction = dir;
-            if let Some(pos) = position.move_in(dir) {
-                position = pos;
-            } else {
-                break;
-            }
         }
+        heads = next_heads;
     }
 
-    energized.len()
+    energized
 }
 
-pub fn solve_part_1(input: &str) -> usize {
-    let grid = parse_input(input);
+fn count_energized(grid: &Grid<Tile>, start_pos: Pos, start_dir: Direction) -> usize {
+    energize(grid, start_pos, start_dir, |_, _| ()).len()
+}
 
-    let start_pos = Position::new(0, 0);
-    let start_dir = Direction::East;
+/// Tiles on the edge of the grid with the direction of a beam entering there.
+fn entry_points(grid: &Grid<Tile>) -> impl Iterator<Item = (Pos, Direction)> + '_ {
+    (0..grid.width)
+        .map(|x| (Pos::new(x, 0), Direction::South))
+        .chain((0..grid.height).map(|y| (Pos::new(0, y), Direction::East)))
+        .chain((0..grid.width).map(|x| (Pos::new(x, grid.height - 1), Direction::North)))
+        .chain((0..grid.height).map(|y| (Pos::new(grid.width - 1, y), Direction::West)))
+}
 
-    // for y in 0..grid.height {
-    //     for x in 0..grid.width {
-    //         let pos = Position::new(x, y);
-    //         let char = if energized.contains(&pos) { '#' } else { '.' };
-    //         print!("{}", char)
-    //     }
-    //     println!()
-    // }
+/// Fails for a grid without tiles, where the beam has nowhere to enter.
+fn check_has_tiles(grid: &Grid<Tile>) -> Result<(), SolveError> {
+    if grid.width == 0 || grid.height == 0 {
+        return Err(SolveError::not_found("tile for the beam to enter"));
+    }
 
-    count_energized(&grid, start_pos, start_dir)
+    Ok(())
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let grid = parse_input(input);
+/// Entry point energizing the most tiles.
+fn best_entry_point(grid: &Grid<Tile>) -> Result<(Pos, Direction), SolveError> {
+    check_has_tiles(grid)?;
 
-    (0..grid.width)
-        .map(|x| (Position::new(x, 0), Direction::South))
-        .chain((0..grid.height).map(|y| (Position::new(0, y), Direction::East)))
-        .chain((0..grid.width).map(|x| (Position::new(x, grid.height - 1), Direction::North)))
-        .chain((0..grid.height).map(|y| (Position::new(grid.width - 1, y), Direction::West)))
-        .map(|(pos, dir)| count_energized(&grid, pos, dir))
-        .max()
-        .unwrap()
+    entry_points(grid)
+        .max_by_key(|&(pos, dir)| count_energized(grid, pos, dir))
+        .ok_or_else(|| SolveError::not_found("tile for the beam to enter"))
+}
+
+fn part_1(grid: &Grid<Tile>) -> Result<usize, SolveError> {
+    check_has_tiles(grid)?;
+
+    Ok(count_energized(grid, Pos::new(0, 0), Direction::East))
+}
+
+fn part_2(grid: &Grid<Tile>) -> Result<usize, SolveError> {
+    let (pos, dir) = best_entry_point(grid)?;
+
+    Ok(count_energized(grid, pos, dir))
+}
+
+const PALETTE: Palette = Palette {
+    tile: Color::rgb(70, 70, 80),
+    visited: Color::rgb(110, 50, 20),
+    ..Palette::DEFAULT
+};
+
+/// Animates the beams spreading from `start_pos`, one step per frame.
+fn animate(grid: &Grid<Tile>, start_pos: Pos, start_dir: Direction) -> Animation {
+    let tiles = Frame::new(grid, |&tile| match tile {
+        Tile::Empty => Cell::new('.', Style::fg(PALETTE.tile)),
+        tile => Cell::new(tile.into(), Style::fg(PALETTE.accent).bold()),
+    });
+
+    // Energized empty tiles are drawn as `#`, so they show without colors as well.
+    let draw_energized = |frame: Frame, energized: &HashSet<Pos>| {
+        let (empty, devices): (Vec<Pos>, Vec<Pos>) = energized
+            .iter()
+            .partition(|&&pos| grid[pos] == Tile::Empty);
+
+        frame
+            .with(&PALETTE.visited(empty).with_glyph('#'))
+            .with(&PALETTE.visited(devices))
+    };
+
+    let mut animation = Animation::new().with_palette(PALETTE);
+    let mut beams = Grid::filled(grid.width, grid.height, 0u8);
+    let energized = energize(grid, start_pos, start_dir, |energized, heads| {
+        let frame = draw_energized(tiles.clone(), energized)
+            .with(&PALETTE.highlight(heads.iter().map(|&(pos, _)| pos)));
+        animation.push(frame);
+
+        for &(pos, _) in heads {
+            beams[pos] += 1;
+        }
+    });
+
+    // The last frame is a heat map of how many beams pass through each tile.
+    let heat_map = (1..=4).fold(draw_energized(tiles, &energized), |frame, count| {
+        let tiles = beams.iter_indices().map(Pos::from).filter(|&pos| beams[pos] == count);
+        let color = PALETTE.visited.lerp(PALETTE.highlight, (count - 1) as f64 / 3.0);
+
+        frame.with(&Overlay::new(tiles, Style::bg(color)))
+    });
+    animation.push(heat_map);
+
+    animation
+}
+
+pub fn solve_part_1(input: &str) -> Result<usize, SolveError> {
+    part_1(&parse_input(input)?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<usize, SolveError> {
+    part_2(&parse_input(input)?)
+}
+
+pub fn visualize_part_1(input: &str) -> Result<Animation, SolveError> {
+    let grid = parse_input(input)?;
+    check_has_tiles(&grid)?;
+
+    Ok(animate(&grid, Pos::new(0, 0), Direction::East))
+}
+
+/// Animates the beam entering at the tile that energizes the most tiles.
+pub fn visualize_part_2(input: &str) -> Result<Animation, SolveError> {
+    let grid = parse_input(input)?;
+    let (pos, dir) = best_entry_point(&grid)?;
+
+    Ok(animate(&grid, pos, dir))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(grid: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(grid).map(Answer::from)
+    }
+
+    fn part_2(grid: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(grid).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_grid_without_tiles() {
+        let grid = Grid::new(3, 0, Vec::new());
+
+        let error = SolveError::not_found("tile for the beam to enter");
+        assert_eq!(part_1(&grid), Err(error.clone()));
+        assert_eq!(part_2(&grid), Err(error));
+    }
 }
@@ -0,0 +1,77 @@
+//! Energized tiles from following every beam a tile at a time.
+
+use std::collections::HashSet;
+
+/// Tile a beam is on and the direction it goes in, east being `(1, 0)`.
+type Beam = ((usize, usize), (isize, isize));
+
+/// Directions a beam going `(dx, dy)` leaves `tile` in.
+fn deflect(tile: u8, (dx, dy): (isize, isize)) -> Vec<(isize, isize)> {
+    match tile {
+        b'/' => vec![(-dy, -dx)],
+        b'\\' => vec![(dy, dx)],
+        b'|' if dx != 0 => vec![(0, -1), (0, 1)],
+        b'-' if dy != 0 => vec![(-1, 0), (1, 0)],
+        _ => vec![(dx, dy)],
+    }
+}
+
+/// Follows every beam one tile at a time, remembering where each has been.
+fn count_energized(grid: &[&[u8]], start: Beam) -> usize {
+    let mut seen = HashSet::from([start]);
+    let mut beams = vec![start];
+    while let Some(((x, y), direction)) = beams.pop() {
+        for (dx, dy) in deflect(grid[y][x], direction) {
+            let (Some(x), Some(y)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else {
+                continue;
+            };
+            if y < grid.len() && x < grid[y].len() && seen.insert(((x, y), (dx, dy))) {
+                beams.push(((x, y), (dx, dy)));
+            }
+        }
+    }
+
+    seen.iter()
+        .map(|&(pos, _)| pos)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub fn part_1(input: &str) -> usize {
+    let grid = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+
+    count_energized(&grid, ((0, 0), (1, 0)))
+}
+
+pub fn part_2(input: &str) -> usize {
+    let grid = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let (width, height) = (grid[0].len(), grid.len());
+
+    let starts = (0..width)
+        .flat_map(|x| [((x, 0), (0, 1)), ((x, height - 1), (0, -1))])
+        .chain((0..height).flat_map(|y| [((0, y), (1, 0)), ((width - 1, y), (-1, 0))]));
+
+    starts
+        .map(|start| count_energized(&grid, start))
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..16) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use aoc_gen::{grid, Rng};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 141;
+
+/// Generates a `size` by `size` map of heat loss values.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    // The ultra crucibles of the second part need room to move four blocks at once.
+    let size = size.max(5);
+
+    grid(size, size, rng, |rng| {
+        char::from_digit(rng.gen_range(1..=9), 10).unwrap()
+    })
+}
@@ -1,32 +1,37 @@
-use std::fs;
+use std::{env, process::ExitCode};
 
+use aoc_core::{input, Solution};
 use day17::*;
 
-fn main() {
-    let contents = fs::read_to_string("day17/input.txt").expect("Failed to read puzzle input");
+fn main() -> ExitCode {
+    let contents = input::load(env!("CARGO_MANIFEST_DIR"), env::args_os().nth(1))
+        .expect("Failed to read puzzle input");
+    let input = match Day17::parse(&contents) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match Day17::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Part 1: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day17::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Part 2: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    ExitCode::SUCCESS
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "2413432311323
-3215453535623
-3255245654254
-3446585845452
-4546657867536
-1438598798454
-4457876987766
-3637877979653
-4654967986887
-4564679986453
-1224686865563
-2546548887735
-4322674655533";
+    const INPUT: &str = include_str!("../example.txt");
 
     const INPUT2: &str = "111111111111
 999999999991
@@ -38,7 +43,7 @@ mod tests {
     fn test_part_1() {
         let sol = solve_part_1(INPUT);
 
-        assert_eq!(sol, 102);
+        assert_eq!(sol, Ok(102));
     }
 
     #[test]
@@ -46,7 +51,7 @@ mod tests {
         let sol = solve_part_2(INPUT);
         let sol2 = solve_part_2(INPUT2);
 
-        assert_eq!(sol, 94);
-        assert_eq!(sol2, 71);
+        assert_eq!(sol, Ok(94));
+        assert_eq!(sol2, Ok(71));
     }
 }
@@ -0,0 +1,96 @@
+//! Least heat loss from relaxing every state of the crucible until none improves.
+
+const DIRECTIONS: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Relaxes the heat lost on the way to every state of the crucible until none improves,
+/// instead of searching in order of heat lost.
+fn least_heat_loss(input: &str, min_run: usize, max_run: usize) -> u64 {
+    let grid = input
+        .lines()
+        .map(|line| {
+            line.bytes()
+                .map(|byte| (byte - b'0') as u64)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let (width, height) = (grid[0].len(), grid.len());
+
+    // Least heat lost reaching each tile going each direction after each run of blocks.
+    let mut loss = vec![vec![vec![vec![u64::MAX; max_run + 1]; 4]; width]; height];
+    loss[0][0][1][0] = 0;
+    loss[0][0][2][0] = 0;
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for y in 0..height {
+            for x in 0..width {
+                for direction in 0..4 {
+                    for run in 0..=max_run {
+                        let current = loss[y][x][direction][run];
+                        if current == u64::MAX {
+                            continue;
+                        }
+
+                        for turn in 0..4 {
+                            let next_run = if turn == direction { run + 1 } else { 1 };
+                            let allowed = if turn == direction {
+                                run < max_run
+                            } else {
+                                turn != (direction + 2) % 4 && run >= min_run
+                            };
+                            let (dx, dy) = DIRECTIONS[turn];
+                            let (Some(nx), Some(ny)) =
+                                (x.checked_add_signed(dx), y.checked_add_signed(dy))
+                            else {
+                                continue;
+                            };
+                            if !allowed || nx >= width || ny >= height {
+                                continue;
+                            }
+
+                            let next = current + grid[ny][nx];
+                            if next < loss[ny][nx][turn][next_run] {
+                                loss[ny][nx][turn][next_run] = next;
+                                improved = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    loss[height - 1][width - 1]
+        .iter()
+        .flat_map(|runs| runs[min_run..].iter().copied())
+        .min()
+        .unwrap()
+}
+
+pub fn part_1(input: &str) -> u64 {
+    least_heat_loss(input, 1, 3)
+}
+
+pub fn part_2(input: &str) -> u64 {
+    least_heat_loss(input, 4, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 5usize..10) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
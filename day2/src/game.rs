@@ -1,5 +1,5 @@
 #[derive(Debug)]
-pub(crate) struct Game {
+pub struct Game {
     pub id: u32,
     pub sets: Vec<CubeSet>,
 }
@@ -11,14 +11,14 @@ impl Game {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-pub(crate) enum CubeColor {
+pub enum CubeColor {
     Red,
     Green,
     Blue,
 }
 
 #[derive(Debug)]
-pub(crate) struct CubeSet {
+pub struct CubeSet {
     pub red: u32,
     pub green: u32,
     pub blue: u32,
@@ -0,0 +1,30 @@
+use aoc_gen::{Rng, SliceRandom};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 100;
+
+/// Generates `size` games with up to six sets of at most 20 cubes of each color.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            let sets = (0..rng.gen_range(1..=6))
+                .map(|_| cube_set(rng))
+                .collect::<Vec<_>>();
+
+            format!("Game {id}: {}", sets.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn cube_set(rng: &mut impl Rng) -> String {
+    let mut colors = ["red", "green", "blue"];
+    colors.shuffle(rng);
+
+    let count = rng.gen_range(1..=3);
+    colors[..count]
+        .iter()
+        .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -1,18 +1,22 @@
 mod game;
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
-use game::CubeSet;
+pub use generate::{generate, INPUT_SIZE};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use game::{CubeSet, Game};
 use parser::parse_input;
 
 fn check_cube_count(set: &CubeSet, red: u32, green: u32, blue: u32) -> bool {
     set.red <= red && set.green <= green && set.blue <= blue
 }
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let games = parse_input(input);
-
+fn part_1(games: &[Game]) -> u32 {
     games
-        .into_iter()
+        .iter()
         .filter(|game| {
             game.sets
                 .iter()
@@ -22,13 +26,11 @@ pub fn solve_part_1(input: &str) -> u32 {
         .sum()
 }
 
-pub fn solve_part_2(input: &str) -> u32 {
-    let games = parse_input(input);
-
-    games.into_iter()
+fn part_2(games: &[Game]) -> u32 {
+    games.iter()
         .map(|game| {
             let mut minimal_set = CubeSet::new(0, 0, 0);
-            for set in game.sets {
+            for set in &game.sets {
                 minimal_set.red = minimal_set.red.max(set.red);
                 minimal_set.green = minimal_set.green.max(set.green);
                 minimal_set.blue = minimal_set.blue.max(set.blue);
@@ -37,3 +39,29 @@ pub fn solve_part_2(input: &str) -> u32 {
         })
         .sum()
 }
+
+pub fn solve_part_1(input: &str) -> Result<u32, SolveError> {
+    Ok(part_1(&parse_input(input)?))
+}
+
+pub fn solve_part_2(input: &str) -> Result<u32, SolveError> {
+    Ok(part_2(&parse_input(input)?))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(games: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_1(games).into())
+    }
+
+    fn part_2(games: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_2(games).into())
+    }
+}
@@ -1,21 +1,21 @@
+use aoc_core::{
+    parse::{lines, parse_all},
+    ParseError,
+};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, space1, line_ending},
+    character::complete::{digit1, space1},
     combinator::{map, map_res},
     multi::separated_list1,
     sequence::{preceded, separated_pair, tuple},
-    IResult, Finish,
+    IResult,
 };
 
 use crate::game::{CubeColor, CubeSet, Game};
 
-pub(crate) fn parse_input(input: &str) -> Vec<Game> {
-    let (_, games) = game_list(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    games
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse_all(input, game_list)
 }
 
 fn integer(input: &str) -> IResult<&str, u32> {
@@ -59,7 +59,7 @@ fn game(input: &str) -> IResult<&str, Game> {
 }
 
 fn game_list(input: &str) -> IResult<&str, Vec<Game>> {
-    separated_list1(line_ending, game)(input)
+    lines(game)(input)
 }
 
 #[cfg(test)]
@@ -0,0 +1,58 @@
+//! Cube game answers from the largest count of each color, read by splitting strings.
+
+/// Largest number of red, green and blue cubes shown at once in each game, with its id.
+fn games(input: &str) -> Vec<(u32, [u32; 3])> {
+    input
+        .lines()
+        .map(|line| {
+            let (game, sets) = line.split_once(": ").unwrap();
+            let id = game.trim_start_matches("Game ").parse().unwrap();
+
+            let mut max = [0; 3];
+            for cubes in sets.split([',', ';']) {
+                let (count, color) = cubes.trim().split_once(' ').unwrap();
+                let index = ["red", "green", "blue"]
+                    .iter()
+                    .position(|&name| name == color)
+                    .unwrap();
+                max[index] = max[index].max(count.parse().unwrap());
+            }
+
+            (id, max)
+        })
+        .collect()
+}
+
+pub fn part_1(input: &str) -> u32 {
+    games(input)
+        .into_iter()
+        .filter(|&(_, [red, green, blue])| red <= 12 && green <= 13 && blue <= 14)
+        .map(|(id, _)| id)
+        .sum()
+}
+
+pub fn part_2(input: &str) -> u32 {
+    games(input)
+        .into_iter()
+        .map(|(_, max)| max.iter().product::<u32>())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..20) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -1,17 +1,19 @@
 use std::str::FromStr;
 
+use aoc_core::ParseError;
+
 #[derive(Debug, Copy, Clone)]
-pub(crate) enum EngineCell {
+pub enum EngineCell {
     Number(usize, u32),
     Symbol(usize, char),
     Empty,
 }
 
 impl EngineCell {
-    pub fn get_number(&self) -> u32 {
+    pub fn get_number(&self) -> Option<u32> {
         match self {
-            EngineCell::Number(_, num) => *num,
-            _ => panic!()
+            EngineCell::Number(_, num) => Some(*num),
+            _ => None
         }
     }
 }
@@ -51,7 +53,8 @@ impl Ord for EngineCell {
     }
 }
 
-pub(crate) struct EngineSchematic {
+#[derive(Debug)]
+pub struct EngineSchematic {
     pub width: usize,
     pub height: usize,
     pub cells: Vec<EngineCell>,
@@ -78,31 +81,43 @@ impl EngineSchematic {
 }
 
 impl FromStr for EngineSchematic {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let lines: Vec<&str> = input.lines().collect();
-        let width = lines[0].len();
+        let Some(first_line) = lines.first().filter(|line| !line.is_empty()) else {
+            return Err(ParseError::at_position(input, 0, 0, "at least one cell"));
+        };
+        let width = first_line.chars().count();
         let height = lines.len();
 
         let mut cells = Vec::with_capacity(width * height);
         let mut part_id = 0;
 
-        for line in lines {
-            let mut current_num = 0;
-            for char in line.chars() {
+        for (y, line) in lines.into_iter().enumerate() {
+            let mut current_num = 0u32;
+            let mut num_len = 0;
+            for (x, char) in line.chars().enumerate() {
+                if x >= width {
+                    return Err(ParseError::at_position(input, y, x, format!("row of {width} cells")));
+                }
+
                 match char {
                     '0'..='9' => {
                         let digit = char.to_digit(10).unwrap();
-                        current_num = current_num * 10 + digit;
+                        let Some(num) = current_num.checked_mul(10).and_then(|num| num.checked_add(digit)) else {
+                            return Err(ParseError::at_position(input, y, x - num_len, "number that fits in 32 bits"));
+                        };
+                        current_num = num;
+                        num_len += 1;
                     }
-                    _ if current_num > 0 => {
-                        let len = current_num.ilog10() + 1;
-                        for _ in 0..len {
+                    _ if num_len > 0 => {
+                        for _ in 0..num_len {
                             cells.push(EngineCell::Number(part_id, current_num));
                         }
                         part_id += 1;
                         current_num = 0;
+                        num_len = 0;
                     }
                     _ => (),
                 }
@@ -117,16 +132,18 @@ impl FromStr for EngineSchematic {
                 }
             }
 
-            if current_num > 0 {
-                let len = current_num.ilog10() + 1;
-                for _ in 0..len {
+            if num_len > 0 {
+                for _ in 0..num_len {
                     cells.push(EngineCell::Number(part_id, current_num));
                 }
                 part_id += 1;
             }
-        }
 
-        assert_eq!(cells.len(), width * height);
+            if cells.len() != (y + 1) * width {
+                let row_width = line.chars().count();
+                return Err(ParseError::at_position(input, y, row_width, format!("row of {width} cells")));
+            }
+        }
 
         Ok(Self {
             width,
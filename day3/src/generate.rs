@@ -0,0 +1,36 @@
+use aoc_gen::{weighted, Rng, SliceRandom};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 140;
+
+const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+
+/// Generates a `size` by `size` engine schematic of part numbers and symbols.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+
+    (0..size)
+        .map(|_| {
+            let mut row = String::with_capacity(size);
+            while row.len() < size {
+                match weighted(rng, &[(0, 6), (1, 2), (2, 1)]) {
+                    0 => row.push('.'),
+                    1 => {
+                        // Numbers are followed by a separator so that they do not run together.
+                        let digits = rng.gen_range(1..=3).min(size - row.len());
+                        let number =
+                            rng.gen_range(10u32.pow(digits as u32 - 1)..10u32.pow(digits as u32));
+                        row.push_str(&number.to_string());
+                        if row.len() < size {
+                            row.push('.');
+                        }
+                    }
+                    _ => row.push(*SYMBOLS.choose(rng).unwrap() as char),
+                }
+            }
+
+            row
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,40 +1,45 @@
 mod engine_schematic;
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+pub use generate::{generate, INPUT_SIZE};
 
 use std::collections::BTreeSet;
 
-use engine_schematic::EngineCell;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use engine_schematic::{EngineCell, EngineSchematic};
 use parser::parse_input;
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let schematic = parse_input(input);
-
+fn part_1(schematic: &EngineSchematic) -> Result<u32, SolveError> {
     let mut set = BTreeSet::new();
     for y in 0..schematic.height {
         for x in 0..schematic.width {
             let cell = schematic.get_cell(x, y);
             match cell {
-                EngineCell::Number(_, _) if !set.contains(&cell) => {
-                    if schematic
-                        .get_surrounding(x, y)
-                        .into_iter()
-                        .any(|cell| matches!(cell, EngineCell::Symbol(_, _)))
-                    {
-                        set.insert(cell);
-                    }
+                EngineCell::Number(_, _)
+                    if !set.contains(&cell)
+                        && schematic
+                            .get_surrounding(x, y)
+                            .into_iter()
+                            .any(|cell| matches!(cell, EngineCell::Symbol(_, _))) =>
+                {
+                    set.insert(cell);
                 }
                 _ => (),
             }
         }
     }
 
-    set.into_iter().map(|cell| cell.get_number()).sum()
+    set.into_iter()
+        .filter_map(|cell| cell.get_number())
+        .try_fold(0u32, |sum, num| sum.checked_add(num))
+        .ok_or_else(|| SolveError::overflow("sum of part numbers"))
 }
 
-pub fn solve_part_2(input: &str) -> u32 {
-    let schematic = parse_input(input);
-
-    let mut result = 0;
+fn part_2(schematic: &EngineSchematic) -> Result<u32, SolveError> {
+    let mut result = 0u32;
     for y in 0..schematic.height {
         for x in 0..schematic.width {
             let cell = schematic.get_cell(x, y);
@@ -45,12 +50,43 @@ pub fn solve_part_2(input: &str) -> u32 {
                     .filter(|cell| matches!(cell, EngineCell::Number(_, _)))
                     .collect();
 
-                if set.len() == 2{
-                    result += set.into_iter().map(|cell| cell.get_number()).product::<u32>();
+                if set.len() == 2 {
+                    result = set
+                        .into_iter()
+                        .filter_map(|cell| cell.get_number())
+                        .try_fold(1u32, |ratio, num| ratio.checked_mul(num))
+                        .and_then(|ratio| result.checked_add(ratio))
+                        .ok_or_else(|| SolveError::overflow("sum of gear ratios"))?;
                 }
             }
         }
     }
 
-    result
+    Ok(result)
+}
+
+pub fn solve_part_1(input: &str) -> Result<u32, SolveError> {
+    part_1(&parse_input(input)?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<u32, SolveError> {
+    part_2(&parse_input(input)?)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = EngineSchematic;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(schematic: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(schematic).map(Answer::from)
+    }
+
+    fn part_2(schematic: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(schematic).map(Answer::from)
+    }
 }
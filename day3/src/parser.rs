@@ -1,8 +1,10 @@
 
+use aoc_core::ParseError;
+
 use crate::engine_schematic::EngineSchematic;
 
-pub(crate) fn parse_input(input: &str) -> EngineSchematic {
-    input.parse().unwrap()
+pub(crate) fn parse_input(input: &str) -> Result<EngineSchematic, ParseError> {
+    input.parse()
 }
 
 #[cfg(test)]
@@ -18,7 +20,7 @@ mod tests {
         let input = "12..3
 .4*..";
 
-        let schematic = parse_input(input);
+        let schematic = parse_input(input).unwrap();
 
         assert_eq!(schematic.width, 5);
         assert_eq!(schematic.height, 2);
@@ -34,4 +36,18 @@ mod tests {
         assert_eq!(surrounding[3], EngineCell::Number(2, 4));
         assert_eq!(surrounding[4], EngineCell::Empty);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+
+        let error = parse_input("12..3\n.4*.").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 5));
+        assert_eq!(error.expected(), "row of 5 cells");
+
+        let error = parse_input("..4294967296.").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 3));
+        assert_eq!(error.expected(), "number that fits in 32 bits");
+    }
 }
@@ -0,0 +1,106 @@
+//! Part numbers and gear ratios from checking every number against every symbol.
+
+/// Number in the schematic, with the row and the columns it spans.
+struct Number {
+    value: u32,
+    y: usize,
+    xs: std::ops::Range<usize>,
+}
+
+impl Number {
+    fn is_adjacent(&self, x: usize, y: usize) -> bool {
+        self.y.abs_diff(y) <= 1 && self.xs.start <= x + 1 && x <= self.xs.end
+    }
+}
+
+fn numbers(rows: &[&[u8]]) -> Vec<Number> {
+    let mut numbers = Vec::new();
+    for (y, row) in rows.iter().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            if row[x].is_ascii_digit() {
+                let start = x;
+                while x < row.len() && row[x].is_ascii_digit() {
+                    x += 1;
+                }
+                let value = std::str::from_utf8(&row[start..x])
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                numbers.push(Number {
+                    value,
+                    y,
+                    xs: start..x,
+                });
+            } else {
+                x += 1;
+            }
+        }
+    }
+
+    numbers
+}
+
+/// Symbols of the schematic with their positions.
+fn symbols(rows: &[&[u8]]) -> Vec<(usize, usize, u8)> {
+    rows.iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(_, &char)| char != b'.' && !char.is_ascii_digit())
+                .map(move |(x, &char)| (x, y, char))
+        })
+        .collect()
+}
+
+pub fn part_1(input: &str) -> u32 {
+    let rows = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let symbols = symbols(&rows);
+
+    numbers(&rows)
+        .into_iter()
+        .filter(|number| symbols.iter().any(|&(x, y, _)| number.is_adjacent(x, y)))
+        .map(|number| number.value)
+        .sum()
+}
+
+pub fn part_2(input: &str) -> u32 {
+    let rows = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let numbers = numbers(&rows);
+
+    symbols(&rows)
+        .into_iter()
+        .filter(|&(_, _, char)| char == b'*')
+        .map(|(x, y, _)| {
+            let adjacent = numbers
+                .iter()
+                .filter(|number| number.is_adjacent(x, y))
+                .collect::<Vec<_>>();
+
+            match adjacent[..] {
+                [a, b] => a.value * b.value,
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..20) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
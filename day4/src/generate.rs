@@ -0,0 +1,68 @@
+use aoc_gen::{weighted, Rng, SliceRandom};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 200;
+
+/// Generates `size` scratchcards with 10 winning numbers and 25 numbers each.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+    let width = size.to_string().len();
+
+    (1..=size)
+        .map(|id| {
+            // Cards win copies of at most the cards after them, and win one on average, so
+            // the number of copies stays far from overflowing.
+            let max_matches = (size - id).min(10);
+            let matches = weighted(rng, &[(0, 12), (1, 3), (2, 3), (3, 2)]).min(max_matches);
+
+            let numbers = aoc_gen::rand::seq::index::sample(rng, 99, 35 - matches)
+                .into_iter()
+                .map(|index| index as u32 + 1)
+                .collect::<Vec<_>>();
+            let winning = &numbers[..10];
+            let mut own = numbers[10..].to_vec();
+            own.extend(winning.choose_multiple(rng, matches));
+            own.shuffle(rng);
+
+            format!(
+                "Card {id:>width$}: {} | {}",
+                number_list(winning),
+                number_list(&own)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn number_list(numbers: &[u32]) -> String {
+    numbers
+        .iter()
+        .map(|number| format!("{number:>2}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use aoc_gen::rng;
+
+    use super::*;
+    use crate::parser::parse_input;
+
+    #[test]
+    fn numbers_are_distinct() {
+        let input = generate(50, &mut rng(3));
+
+        for line in input.lines() {
+            let (winning, own) = line.split_once(": ").unwrap().1.split_once(" | ").unwrap();
+            let winning = winning.split_whitespace().collect::<BTreeSet<_>>();
+            let own = own.split_whitespace().collect::<BTreeSet<_>>();
+
+            assert_eq!(winning.len(), 10);
+            assert_eq!(own.len(), 25);
+        }
+        assert_eq!(parse_input(&input).unwrap().len(), 50);
+    }
+}
@@ -1,29 +1,64 @@
 mod card;
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+pub use generate::{generate, INPUT_SIZE};
 
 use std::collections::HashMap;
 
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use card::ScratchCard;
 use parser::parse_input;
 
-pub fn solve_part_1(input: &str) -> u32 {
-    let cards = parse_input(input);
-
-    cards.into_iter().map(|card| card.score()).sum()
+fn part_1(cards: &[ScratchCard]) -> u32 {
+    cards.iter().map(|card| card.score()).sum()
 }
 
-pub fn solve_part_2(input: &str) -> u32 {
-    let cards = parse_input(input);
-
-    // Maps card id to number of cards that card directly wins.
+fn part_2(cards: &[ScratchCard]) -> Result<u32, SolveError> {
+    // Maps card id to number of cards that card directly wins, with the line of the card.
     let base_won_cards = cards
-        .into_iter()
-        .map(|card| (card.id, card.won_cards()));
+        .iter()
+        .enumerate()
+        .map(|(line, card)| (line, card.id, card.won_cards()));
 
     let mut won_cards = HashMap::with_capacity(base_won_cards.len());
-    for (card_id, base_card_score) in base_won_cards.rev() {
-        let card_score: u32 = (1..=base_card_score).map(|i| won_cards[&(card_id + i as usize)]).sum();
+    for (line, card_id, base_card_score) in base_won_cards.rev() {
+        let card_score = (1..=base_card_score)
+            .map(|i| won_cards.get(&(card_id + i as usize)).copied())
+            .sum::<Option<u32>>()
+            .ok_or_else(|| {
+                SolveError::at_position(line, 0, "card wins copies of cards past the last one")
+            })?;
         won_cards.insert(card_id, base_card_score + card_score);
     }
 
-    won_cards.len() as u32 + won_cards.into_values().sum::<u32>()
+    Ok(won_cards.len() as u32 + won_cards.into_values().sum::<u32>())
+}
+
+pub fn solve_part_1(input: &str) -> Result<u32, SolveError> {
+    Ok(part_1(&parse_input(input)?))
+}
+
+pub fn solve_part_2(input: &str) -> Result<u32, SolveError> {
+    part_2(&parse_input(input)?)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<ScratchCard>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(cards: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_1(cards).into())
+    }
+
+    fn part_2(cards: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(cards).map(Answer::from)
+    }
 }
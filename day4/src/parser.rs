@@ -1,20 +1,20 @@
+use aoc_core::{
+    parse::{lines, parse_all},
+    ParseError,
+};
 use nom::{
     bytes::complete::tag,
-    character::complete::{char, digit1, line_ending, space1},
+    character::complete::{char, digit1, space1},
     combinator::{map, map_res},
     multi::separated_list1,
     sequence::{delimited, terminated, tuple},
-    Finish, IResult,
+    IResult,
 };
 
 use crate::card::ScratchCard;
 
-pub(crate) fn parse_input(input: &str) -> Vec<ScratchCard> {
-    let (_, cards) = card_list(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    cards
+pub(crate) fn parse_input(input: &str) -> Result<Vec<ScratchCard>, ParseError> {
+    parse_all(input, card_list)
 }
 
 fn number(input: &str) -> IResult<&str, u32> {
@@ -45,7 +45,7 @@ fn scratch_card(input: &str) -> IResult<&str, ScratchCard> {
 }
 
 fn card_list(input: &str) -> IResult<&str, Vec<ScratchCard>> {
-    separated_list1(line_ending, scratch_card)(input)
+    lines(scratch_card)(input)
 }
 
 #[cfg(test)]
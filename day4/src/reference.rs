@@ -0,0 +1,57 @@
+//! Scratchcard points, and the cards won by scratching every copy one at a time.
+
+/// Number of winning numbers on each card.
+fn matches(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .map(|line| {
+            let (winning, numbers) = line.split_once(": ").unwrap().1.split_once(" | ").unwrap();
+            let winning = winning.split_whitespace().collect::<Vec<_>>();
+
+            numbers
+                .split_whitespace()
+                .filter(|number| winning.contains(number))
+                .count()
+        })
+        .collect()
+}
+
+pub fn part_1(input: &str) -> u32 {
+    matches(input)
+        .into_iter()
+        .map(|matches| if matches == 0 { 0 } else { 1 << (matches - 1) })
+        .sum()
+}
+
+/// Scratches every copy of every card one at a time.
+pub fn part_2(input: &str) -> u32 {
+    let matches = matches(input);
+
+    let mut scratched = 0;
+    let mut pile = (0..matches.len()).collect::<Vec<_>>();
+    while let Some(card) = pile.pop() {
+        scratched += 1;
+        pile.extend(card + 1..card + 1 + matches[card]);
+    }
+
+    scratched
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..20) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
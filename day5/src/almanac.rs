@@ -1,3 +1,7 @@
+use aoc_interval::IntervalSet;
+
+use crate::range_map::RangeMap;
+
 
 pub trait AlmanacItem: Copy + Ord + Eq {
     fn new(num: u64) -> Self;
@@ -30,3 +34,39 @@ def_almanac_item!(Light);
 def_almanac_item!(Temperature);
 def_almanac_item!(Humidity);
 def_almanac_item!(Location);
+
+pub struct Almanac {
+    pub seeds: Vec<Seed>,
+    pub seed_to_soil: RangeMap<Seed, Soil>,
+    pub soil_to_fertilizer: RangeMap<Soil, Fertilizer>,
+    pub fertilizer_to_water: RangeMap<Fertilizer, Water>,
+    pub water_to_light: RangeMap<Water, Light>,
+    pub light_to_temperature: RangeMap<Light, Temperature>,
+    pub temperature_to_humidity: RangeMap<Temperature, Humidity>,
+    pub humidity_to_location: RangeMap<Humidity, Location>,
+}
+
+impl Almanac {
+    pub fn location(&self, seed: Seed) -> Location {
+        let soil = self.seed_to_soil.get(seed);
+        let fertilizer = self.soil_to_fertilizer.get(soil);
+        let water = self.fertilizer_to_water.get(fertilizer);
+        let light = self.water_to_light.get(water);
+        let temperature = self.light_to_temperature.get(light);
+        let humidity = self.temperature_to_humidity.get(temperature);
+
+        self.humidity_to_location.get(humidity)
+    }
+
+    /// Locations of all seeds in `seeds`, mapping whole ranges at once.
+    pub fn locations(&self, seeds: &IntervalSet) -> IntervalSet {
+        let soil = self.seed_to_soil.map_ranges(seeds);
+        let fertilizer = self.soil_to_fertilizer.map_ranges(&soil);
+        let water = self.fertilizer_to_water.map_ranges(&fertilizer);
+        let light = self.water_to_light.map_ranges(&water);
+        let temperature = self.light_to_temperature.map_ranges(&light);
+        let humidity = self.temperature_to_humidity.map_ranges(&temperature);
+
+        self.humidity_to_location.map_ranges(&humidity)
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+use aoc_gen::{Rng, SliceRandom};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 30;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Generates an almanac with up to `size` seed ranges and `size` ranges per map.
+///
+/// Values grow with the size up to those of real inputs, so that the seeds of small almanacs
+/// can be mapped one by one.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+    let max_value = 1 << (8 * size).min(32);
+
+    let seeds = (0..rng.gen_range(1..=size))
+        .map(|_| {
+            let start = rng.gen_range(0..max_value / 2);
+            let len = rng.gen_range(1..=max_value / 64);
+            format!("{start} {len}")
+        })
+        .collect::<Vec<_>>();
+
+    let mut output = format!("seeds: {}", seeds.join(" "));
+    for name in MAPS {
+        output.push_str(&format!("\n\n{name} map:"));
+        for (destination, source, len) in ranges(size, max_value, rng) {
+            output.push_str(&format!("\n{destination} {source} {len}"));
+        }
+    }
+
+    output
+}
+
+/// Ranges of a map with disjoint sources, in random order.
+fn ranges(size: usize, max_value: u64, rng: &mut impl Rng) -> Vec<(u64, u64, u64)> {
+    let count = rng.gen_range(1..=size);
+    let mut bounds = BTreeSet::new();
+    while bounds.len() < 2 * count {
+        bounds.insert(rng.gen_range(0..max_value));
+    }
+    let bounds = bounds.into_iter().collect::<Vec<_>>();
+
+    let mut ranges = bounds
+        .chunks_exact(2)
+        .map(|bounds| {
+            let (source, len) = (bounds[0], bounds[1] - bounds[0]);
+            (rng.gen_range(0..max_value - len), source, len)
+        })
+        .collect::<Vec<_>>();
+    ranges.shuffle(rng);
+
+    ranges
+}
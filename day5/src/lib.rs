@@ -1,71 +1,69 @@
 mod almanac;
+mod generate;
 mod parser;
 mod range_map;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+pub use generate::{generate, INPUT_SIZE};
 
 use almanac::AlmanacItem;
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_interval::{Interval, IntervalSet};
 use itertools::Itertools;
 use parser::parse_input;
 
-use crate::almanac::Seed;
-
-pub fn solve_part_1(input: &str) -> u64 {
-    let (
-        seeds,
-        seed_to_soil,
-        soild_to_fertilizer,
-        fertilizer_to_water,
-        water_to_light,
-        light_to_temperature,
-        temperature_to_humidity,
-        humidity_to_location,
-    ) = parse_input(input);
+use crate::almanac::Almanac;
 
-    seeds
-        .into_iter()
-        .map(|seed| seed_to_soil.get(seed))
-        .map(|soil| soild_to_fertilizer.get(soil))
-        .map(|fertilizer| fertilizer_to_water.get(fertilizer))
-        .map(|water| water_to_light.get(water))
-        .map(|light| light_to_temperature.get(light))
-        .map(|temperature| temperature_to_humidity.get(temperature))
-        .map(|humidity| humidity_to_location.get(humidity))
+fn part_1(almanac: &Almanac) -> Result<u64, SolveError> {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| almanac.location(seed))
         .map(|location| location.value())
         .min()
-        .unwrap()
+        .ok_or_else(|| SolveError::not_found("seeds"))
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let (
-        seeds,
-        seed_to_soil,
-        soild_to_fertilizer,
-        fertilizer_to_water,
-        water_to_light,
-        light_to_temperature,
-        temperature_to_humidity,
-        humidity_to_location,
-    ) = parse_input(input);
-
-    println!("Input parsed");
-
-    let seeds = seeds
-        .into_iter()
+fn part_2(almanac: &Almanac) -> Result<u64, SolveError> {
+    let seeds = almanac
+        .seeds
+        .iter()
         .tuples()
-        .flat_map(|(start, len)| (start.value()..(start.value() + len.value())).map(Seed::new))
-        .collect_vec();
+        .map(|(start, len)| Interval::from_len(start.value() as i64, len.value() as i64))
+        .collect::<IntervalSet>();
 
-    println!("Seeds generated");
+    log::debug!("Mapping {} seeds in {} ranges", seeds.len(), seeds.iter().count());
 
-    seeds
-        .into_iter()
-        .map(|seed| seed_to_soil.get(seed))
-        .map(|soil| soild_to_fertilizer.get(soil))
-        .map(|fertilizer| fertilizer_to_water.get(fertilizer))
-        .map(|water| water_to_light.get(water))
-        .map(|light| light_to_temperature.get(light))
-        .map(|temperature| temperature_to_humidity.get(temperature))
-        .map(|humidity| humidity_to_location.get(humidity))
-        .map(|location| location.value())
+    almanac
+        .locations(&seeds)
         .min()
-        .unwrap()
+        .map(|location| location as u64)
+        .ok_or_else(|| SolveError::not_found("seed ranges"))
+}
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    part_1(&parse_input(input)?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<u64, SolveError> {
+    part_2(&parse_input(input)?)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(almanac: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(almanac).map(Answer::from)
+    }
+
+    fn part_2(almanac: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(almanac).map(Answer::from)
+    }
 }
@@ -1,37 +1,27 @@
 #![allow(clippy::complexity)]
 
+use aoc_core::{
+    parse::{lines, parse_all},
+    ParseError,
+};
 use nom::{
     bytes::complete::tag,
     character::complete::{digit1, line_ending, space1},
-    combinator::{map, map_res},
+    combinator::{map, map_opt, map_res},
     multi::{many0, many1, separated_list1},
     sequence::{delimited, preceded, terminated, tuple},
-    Finish, IResult,
+    IResult,
 };
 
 use crate::{almanac::*, range_map::RangeMap};
 
-pub(crate) fn parse_input(
-    input: &str,
-) -> (
-    Vec<Seed>,
-    RangeMap<Seed, Soil>,
-    RangeMap<Soil, Fertilizer>,
-    RangeMap<Fertilizer, Water>,
-    RangeMap<Water, Light>,
-    RangeMap<Light, Temperature>,
-    RangeMap<Temperature, Humidity>,
-    RangeMap<Humidity, Location>,
-) {
-    let (_, almanac) = almanac(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    almanac
+pub(crate) fn parse_input(input: &str) -> Result<Almanac, ParseError> {
+    parse_all(input, almanac)
 }
 
 fn number(input: &str) -> IResult<&str, u64> {
-    map_res(digit1, str::parse)(input)
+    // Values are mapped as signed offsets, so they must fit into an i64.
+    map_res(map_res(digit1, str::parse::<i64>), u64::try_from)(input)
 }
 
 fn almanac_item<T: AlmanacItem>(input: &str) -> IResult<&str, T> {
@@ -54,28 +44,26 @@ fn seeds(input: &str) -> IResult<&str, Vec<Seed>> {
 }
 
 fn range_map<K: AlmanacItem, V: AlmanacItem>(input: &str) -> IResult<&str, RangeMap<K, V>> {
-    map(
-        separated_list1(line_ending, almanac_range::<K, V>),
+    map_opt(
+        lines(almanac_range::<K, V>),
         RangeMap::new,
     )(input)
 }
 
-fn almanac(
-    input: &str,
-) -> IResult<
-    &str,
-    (
-        Vec<Seed>,
-        RangeMap<Seed, Soil>,
-        RangeMap<Soil, Fertilizer>,
-        RangeMap<Fertilizer, Water>,
-        RangeMap<Water, Light>,
-        RangeMap<Light, Temperature>,
-        RangeMap<Temperature, Humidity>,
-        RangeMap<Humidity, Location>,
-    ),
-> {
-    tuple((
+fn almanac(input: &str) -> IResult<&str, Almanac> {
+    let (
+        input,
+        (
+            seeds,
+            seed_to_soil,
+            soil_to_fertilizer,
+            fertilizer_to_water,
+            water_to_light,
+            light_to_temperature,
+            temperature_to_humidity,
+            humidity_to_location,
+        ),
+    ) = tuple((
         terminated(seeds, many1(line_ending)),
         delimited(
             tuple((tag("seed-to-soil map:"), line_ending)),
@@ -112,7 +100,20 @@ fn almanac(
             range_map::<Humidity, Location>,
             many0(line_ending),
         ),
-    ))(input)
+    ))(input)?;
+
+    let almanac = Almanac {
+        seeds,
+        seed_to_soil,
+        soil_to_fertilizer,
+        fertilizer_to_water,
+        water_to_light,
+        light_to_temperature,
+        temperature_to_humidity,
+        humidity_to_location,
+    };
+
+    Ok((input, almanac))
 }
 
 #[cfg(test)]
@@ -163,4 +164,11 @@ mod tests {
         assert_eq!(range_map.get(Seed(98)), Soil(98));
         assert!(rem.is_empty());
     }
+
+    #[test]
+    fn reject_overlapping_ranges() {
+        let input = "52 50 48\n10 60 5";
+
+        assert!(range_map::<Seed, Soil>(input).is_err());
+    }
 }
@@ -1,28 +1,36 @@
+use std::marker::PhantomData;
+
+use aoc_interval::{Interval, IntervalSet};
+
 use crate::almanac::AlmanacItem;
 
 pub struct RangeMap<K, V> {
-    ranges: Vec<(K, V, u64)>,
+    map: aoc_interval::RangeMap,
+    items: PhantomData<(K, V)>,
 }
 
 impl<K: AlmanacItem, V: AlmanacItem> RangeMap<K, V> {
-    pub fn new(ranges: impl IntoIterator<Item = (V, K, u64)>) -> Self {
-        let mut ranges = ranges
-            .into_iter()
-            .map(|(v, k, len)| (k, v, len))
-            .collect::<Vec<_>>();
+    /// Returns `None` if any two source ranges overlap.
+    pub fn new(ranges: impl IntoIterator<Item = (V, K, u64)>) -> Option<Self> {
+        let mut map = aoc_interval::RangeMap::new();
+        for (v, k, len) in ranges {
+            let start = i64::try_from(k.value()).ok()?;
+            let end = start.checked_add(i64::try_from(len).ok()?)?;
+            let offset = i64::try_from(v.value()).ok()? - start;
 
-        ranges.sort_by_key(|(k, _, _)| *k);
+            if !map.insert(Interval::new(start, end), offset) {
+                return None;
+            }
+        }
 
-        Self { ranges }
+        Some(Self { map, items: PhantomData })
     }
 
     pub fn get(&self, key: K) -> V {
-        let value = self.ranges
-            .iter()
-            .find(|(k, _, len)| *k <= key && key.value() < k.value() + len)
-            .map(|(k, v, _)| v.value() + key.value() - k.value())
-            .unwrap_or(key.value());
+        V::new(self.map.get(key.value() as i64) as u64)
+    }
 
-        V::new(value)
+    pub fn map_ranges(&self, keys: &IntervalSet) -> IntervalSet {
+        self.map.map_ranges(keys)
     }
 }
@@ -0,0 +1,75 @@
+//! Seed locations from mapping one seed at a time, every seed of the ranges in part 2.
+
+/// Seeds and the ranges of each map, as destination start, source start and length.
+fn almanac(input: &str) -> (Vec<u64>, Vec<Vec<[u64; 3]>>) {
+    let mut sections = input.split("\n\n");
+    let seeds = sections.next().unwrap().trim_start_matches("seeds: ");
+    let seeds = seeds.split(' ').map(|seed| seed.parse().unwrap()).collect();
+
+    let maps = sections
+        .map(|section| {
+            section
+                .lines()
+                .skip(1)
+                .map(|line| {
+                    let numbers = line.split(' ').map(|number| number.parse().unwrap());
+                    numbers.collect::<Vec<_>>().try_into().unwrap()
+                })
+                .collect()
+        })
+        .collect();
+
+    (seeds, maps)
+}
+
+fn location(maps: &[Vec<[u64; 3]>], seed: u64) -> u64 {
+    maps.iter().fold(seed, |value, ranges| {
+        ranges
+            .iter()
+            .find(|&&[_, source, len]| (source..source + len).contains(&value))
+            .map_or(value, |&[destination, source, _]| {
+                destination + (value - source)
+            })
+    })
+}
+
+pub fn part_1(input: &str) -> u64 {
+    let (seeds, maps) = almanac(input);
+
+    seeds
+        .into_iter()
+        .map(|seed| location(&maps, seed))
+        .min()
+        .unwrap()
+}
+
+/// Maps every single seed of the ranges.
+pub fn part_2(input: &str) -> u64 {
+    let (seeds, maps) = almanac(input);
+
+    seeds
+        .chunks(2)
+        .flat_map(|range| range[0]..range[0] + range[1])
+        .map(|seed| location(&maps, seed))
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..=2) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use aoc_gen::Rng;
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 4;
+
+/// Generates `size` races, at most four, whose records can be beaten.
+///
+/// The numbers of all races put together form the race of the second part, so it must fit
+/// into a `u64` and have a record that can be beaten too.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let count = size.clamp(1, 4);
+
+    loop {
+        let races = (0..count)
+            .map(|_| {
+                let time: u64 = rng.gen_range(7..100);
+                let record = rng.gen_range(0..time * time / 4);
+                (time, record)
+            })
+            .collect::<Vec<_>>();
+
+        let concat = |numbers: Vec<String>| numbers.concat().parse::<u64>().ok();
+        let time = concat(races.iter().map(|(time, _)| time.to_string()).collect());
+        let record = concat(races.iter().map(|(_, record)| record.to_string()).collect());
+        let beatable = match (time, record) {
+            (Some(time), Some(record)) => (time / 2)
+                .checked_mul(time - time / 2)
+                .is_some_and(|distance| distance > record),
+            _ => false,
+        };
+
+        if beatable {
+            let column = |number: u64| format!("{number:>5}");
+            let times = races
+                .iter()
+                .map(|&(time, _)| column(time))
+                .collect::<String>();
+            let records = races
+                .iter()
+                .map(|&(_, record)| column(record))
+                .collect::<String>();
+
+            return format!("Time:    {times}\nDistance:{records}");
+        }
+    }
+}
@@ -1,5 +1,12 @@
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
+pub use generate::{generate, INPUT_SIZE};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_math::isqrt;
 use parser::parse_input;
 
 pub struct Race {
@@ -8,28 +15,44 @@ pub struct Race {
 }
 
 fn count_winning(race: &Race) -> u64 {
-    let t = race.time as f64;
-    let d = race.record_distance as f64;
-    let delta = t * t - 4.0 * d;
-    let delta_sqrt = delta.sqrt();
+    let t = race.time as u128;
+    let d = race.record_distance as u128;
+    let beats_record = |hold: u128| hold * (t - hold) > d;
 
-    let b_0 = (t - delta_sqrt) / 2.0;
-    let b_1 = (t + delta_sqrt) / 2.0;
+    // Winning hold times lie strictly between the roots of `hold * (t - hold) = d`.
+    let Some(delta) = (t * t).checked_sub(4 * d) else {
+        return 0;
+    };
 
-    (b_1.ceil() as u64 - 1).saturating_sub(b_0.floor() as u64 + 1) + 1
-}
+    // Starts at most one past the first winning hold time, which is just above the smaller
+    // root, so stepping back and forth finds it exactly.
+    let mut hold = (t - isqrt(delta)) / 2;
+    while hold > 0 && beats_record(hold - 1) {
+        hold -= 1;
+    }
+    while hold <= t / 2 && !beats_record(hold) {
+        hold += 1;
+    }
 
-pub fn solve_part_1(input: &str) -> u64 {
-    let races = parse_input(input);
+    if hold > t / 2 {
+        return 0;
+    }
 
-    races.into_iter().map(|race| count_winning(&race)).product()
+    // Winning hold times are symmetric around `t / 2`.
+    (t - 2 * hold + 1) as u64
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let races = parse_input(input);
+fn part_1(races: &[Race]) -> Result<u64, SolveError> {
+    races
+        .iter()
+        .map(count_winning)
+        .try_fold(1u64, |product, count| product.checked_mul(count))
+        .ok_or_else(|| SolveError::overflow("product of the ways to win"))
+}
 
+fn part_2(races: &[Race]) -> Result<u64, SolveError> {
     let (time, dist): (String, String) = races
-        .into_iter()
+        .iter()
         .map(|race| (race.time, race.record_distance))
         .fold(
             (String::new(), String::new()),
@@ -39,9 +62,68 @@ pub fn solve_part_2(input: &str) -> u64 {
         );
 
     let race = Race {
-        time: time.parse().unwrap(),
-        record_distance: dist.parse().unwrap(),
+        time: time
+            .parse()
+            .map_err(|_| SolveError::overflow("time of the single race"))?,
+        record_distance: dist
+            .parse()
+            .map_err(|_| SolveError::overflow("record distance of the single race"))?,
     };
 
-    count_winning(&race)
+    Ok(count_winning(&race))
+}
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    part_1(&parse_input(input)?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<u64, SolveError> {
+    part_2(&parse_input(input)?)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Vec<Race>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(races: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(races).map(Answer::from)
+    }
+
+    fn part_2(races: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(races).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn count_winning_matches_brute_force(time in 0u64..2000, record_distance in 0u64..1_000_000) {
+            let race = Race { time, record_distance };
+            let brute_force = (0..=time)
+                .filter(|hold| hold * (time - hold) > record_distance)
+                .count() as u64;
+
+            prop_assert_eq!(count_winning(&race), brute_force);
+        }
+    }
+
+    #[test]
+    fn count_winning_without_precision_loss() {
+        let race = Race {
+            time: 3_000_000_000,
+            record_distance: 2_249_999_999_999_999_999,
+        };
+
+        assert_eq!(count_winning(&race), 1);
+    }
 }
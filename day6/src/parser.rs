@@ -1,18 +1,20 @@
+use aoc_core::{
+    parse::{parse_all},
+    ParseError,
+};
 use nom::{
     bytes::complete::tag,
     character::complete::{digit1, line_ending, space1},
     combinator::{map, map_res},
     multi::separated_list1,
     sequence::{preceded, separated_pair, tuple},
-    Finish, IResult,
+    IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Vec<Race> {
-    let (_, races) = races(input).finish().expect("Failed to parse puzzle input");
-
-    races
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Race>, ParseError> {
+    parse_all(input, races)
 }
 
 fn number(input: &str) -> IResult<&str, u64> {
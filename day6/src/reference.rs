@@ -0,0 +1,55 @@
+//! Ways to win a race from trying every hold time instead of solving the quadratic.
+
+/// Numbers of the time and distance lines.
+fn lines(input: &str) -> [Vec<&str>; 2] {
+    let mut lines = input
+        .lines()
+        .map(|line| line.split_whitespace().skip(1).collect());
+    [lines.next().unwrap(), lines.next().unwrap()]
+}
+
+/// Tries every hold time.
+fn count_winning(time: u64, record: u64) -> u64 {
+    (0..=time)
+        .filter(|hold| hold * (time - hold) > record)
+        .count() as u64
+}
+
+pub fn part_1(input: &str) -> u64 {
+    let [times, records] = lines(input);
+
+    times
+        .iter()
+        .zip(records)
+        .map(|(time, record)| count_winning(time.parse().unwrap(), record.parse().unwrap()))
+        .product()
+}
+
+pub fn part_2(input: &str) -> u64 {
+    let [times, records] = lines(input);
+
+    count_winning(
+        times.concat().parse().unwrap(),
+        records.concat().parse().unwrap(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        // Two races at most, so that every hold time of the second part can be tried.
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..=2) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -47,7 +47,7 @@ impl TryFrom<char> for Card {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Hand {
     pub cards: [Card; 5],
 }
@@ -153,7 +153,7 @@ impl Ord for Hand {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Game {
     pub hand: Hand,
     pub bid: u64,
@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+
+use aoc_gen::{Rng, SliceRandom};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 1000;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Generates `size` distinct hands of camel cards with their bids.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let mut hands = HashSet::new();
+    let mut games = Vec::with_capacity(size.max(1));
+
+    while games.len() < size.max(1) {
+        // Draws from a few cards at a time so that pairs and better hands are common.
+        let distinct = rng.gen_range(1..=5);
+        let choices = CARDS
+            .choose_multiple(rng, distinct)
+            .copied()
+            .collect::<Vec<_>>();
+        let hand = (0..5)
+            .map(|_| *choices.choose(rng).unwrap() as char)
+            .collect::<String>();
+
+        if hands.insert(hand.clone()) {
+            games.push(format!("{hand} {}", rng.gen_range(1..1000)));
+        }
+    }
+
+    games.join("\n")
+}
@@ -1,23 +1,27 @@
 mod camel_cards;
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
-use camel_cards::Card;
+pub use generate::{generate, INPUT_SIZE};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use camel_cards::{Card, Game};
 use itertools::Itertools;
 use parser::parse_input;
 
-pub fn solve_part_1(input: &str) -> u64 {
-    let games = parse_input(input);
-
+fn part_1(games: &[Game]) -> u64 {
     games
-        .into_iter()
+        .iter()
         .sorted_by(|a, b| a.hand.cmp(&b.hand))
         .enumerate()
         .map(|(rank, game)| (rank + 1) as u64 * game.bid)
         .sum()
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let mut games = parse_input(input);
+fn part_2(games: &[Game]) -> u64 {
+    let mut games = games.to_vec();
 
     // Replace jacks with jokers.
     for game in &mut games {
@@ -35,3 +39,29 @@ pub fn solve_part_2(input: &str) -> u64 {
         .map(|(rank, game)| (rank + 1) as u64 * game.bid)
         .sum()
 }
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    Ok(part_1(&parse_input(input)?))
+}
+
+pub fn solve_part_2(input: &str) -> Result<u64, SolveError> {
+    Ok(part_2(&parse_input(input)?))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(games: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_1(games).into())
+    }
+
+    fn part_2(games: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        Ok(part_2(games).into())
+    }
+}
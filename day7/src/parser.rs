@@ -1,19 +1,19 @@
+use aoc_core::{
+    parse::{lines, parse_all},
+    ParseError,
+};
 use nom::{
-    character::complete::{digit1, line_ending, one_of, space1},
+    character::complete::{digit1, one_of, space1},
     combinator::{map, map_res},
-    multi::{count, separated_list1},
+    multi::count,
     sequence::separated_pair,
-    Finish, IResult,
+    IResult,
 };
 
 use crate::camel_cards::{Card, Game, Hand};
 
-pub(crate) fn parse_input(input: &str) -> Vec<Game> {
-    let (_, games) = game_list(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    games
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    parse_all(input, game_list)
 }
 
 fn number(input: &str) -> IResult<&str, u64> {
@@ -35,7 +35,7 @@ fn game(input: &str) -> IResult<&str, Game> {
 }
 
 fn game_list(input: &str) -> IResult<&str, Vec<Game>> {
-    separated_list1(line_ending, game)(input)
+    lines(game)(input)
 }
 
 #[cfg(test)]
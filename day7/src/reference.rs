@@ -0,0 +1,93 @@
+//! Camel card winnings with jokers standing in for every card in turn.
+
+use itertools::Itertools;
+
+const CARDS: &str = "23456789TJQKA";
+const CARDS_WITH_JOKERS: &str = "J23456789TQKA";
+
+/// Strength of the type of a hand, from the sorted counts of its cards.
+fn hand_type(hand: &[char]) -> Vec<usize> {
+    let mut counts = CARDS
+        .chars()
+        .map(|card| hand.iter().filter(|&&other| other == card).count())
+        .filter(|&count| count > 0)
+        .collect::<Vec<_>>();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    counts
+}
+
+/// Strongest type of a hand over every choice of cards for its jokers to stand for.
+fn joker_hand_type(hand: &[char]) -> Vec<usize> {
+    let others = hand
+        .iter()
+        .copied()
+        .filter(|&card| card != 'J')
+        .collect::<Vec<_>>();
+    let jokers = hand.len() - others.len();
+
+    // Which joker stands for which card makes no difference to the type.
+    CARDS
+        .chars()
+        .filter(|&card| card != 'J')
+        .combinations_with_replacement(jokers)
+        .map(|cards| hand_type(&[others.as_slice(), &cards].concat()))
+        .max()
+        .unwrap()
+}
+
+fn winnings(input: &str, jokers: bool) -> u64 {
+    let order = if jokers { CARDS_WITH_JOKERS } else { CARDS };
+    let mut hands = input
+        .lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ').unwrap();
+            let hand = hand.chars().collect::<Vec<_>>();
+            let hand_type = if jokers {
+                joker_hand_type(&hand)
+            } else {
+                hand_type(&hand)
+            };
+            let strengths = hand
+                .iter()
+                .map(|&card| order.find(card).unwrap())
+                .collect::<Vec<_>>();
+
+            ((hand_type, strengths), bid.parse::<u64>().unwrap())
+        })
+        .collect::<Vec<_>>();
+    hands.sort();
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(rank, (_, bid))| (rank as u64 + 1) * bid)
+        .sum()
+}
+
+pub fn part_1(input: &str) -> u64 {
+    winnings(input, false)
+}
+
+pub fn part_2(input: &str) -> u64 {
+    winnings(input, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..20) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use aoc_gen::{Rng, SliceRandom};
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 280;
+
+const NAME_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Generates a map with `size` moves, shaped like real inputs.
+///
+/// Every ghost starts on a node ending in `A` and walks a loop whose length is a multiple of
+/// the number of moves, meeting its node ending in `Z` once per loop. The first ghost starts on
+/// `AAA` and loops through `ZZZ`. Nodes of loops branch off to other nodes through the moves
+/// that are not taken, so following the moves matters.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    let moves = (0..size.max(1))
+        .map(|_| *[b'L', b'R'].choose(rng).unwrap() as char)
+        .collect::<Vec<_>>();
+
+    let mut names = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut name = |rng: &mut _, last: Option<u8>| loop {
+        let mut name = (0..3)
+            .map(|_| *NAME_CHARS.choose(rng).unwrap())
+            .collect::<Vec<_>>();
+        if let Some(last) = last {
+            name[2] = last;
+        } else if matches!(name[2], b'A' | b'Z') {
+            continue;
+        }
+
+        let name = String::from_utf8(name).unwrap();
+        if names.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let mut loop_lengths = [1, 2, 3, 4, 5, 6, 7];
+    loop_lengths.shuffle(rng);
+
+    let mut nodes = Vec::new();
+    let mut loops = Vec::new();
+    for (ghost, &loop_length) in loop_lengths[..rng.gen_range(1..=4)].iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (name(rng, Some(b'A')), name(rng, Some(b'Z'))),
+        };
+
+        // The loop starts with the node ending in Z, so it is reached after a full loop.
+        let mut nodes_in_loop = vec![end];
+        for _ in 1..loop_length * moves.len() {
+            nodes_in_loop.push(name(rng, None));
+        }
+        nodes.push((start, nodes_in_loop[1 % nodes_in_loop.len()].clone(), None));
+        loops.push(nodes_in_loop);
+    }
+
+    let all_loop_nodes = loops.concat();
+    for nodes_in_loop in &loops {
+        for (index, node) in nodes_in_loop.iter().enumerate() {
+            let next = nodes_in_loop[(index + 1) % nodes_in_loop.len()].clone();
+            let other = all_loop_nodes.choose(rng).unwrap().clone();
+            nodes.push((
+                node.clone(),
+                next,
+                Some((moves[index % moves.len()], other)),
+            ));
+        }
+    }
+
+    let mut lines = nodes
+        .into_iter()
+        .map(|(node, next, branch)| {
+            let (left, right) = match branch {
+                None => (next.clone(), next),
+                Some(('L', other)) => (next, other),
+                Some((_, other)) => (other, next),
+            };
+            format!("{node} = ({left}, {right})")
+        })
+        .collect::<Vec<_>>();
+    lines.shuffle(rng);
+
+    format!(
+        "{}\n\n{}",
+        moves.iter().collect::<String>(),
+        lines.join("\n")
+    )
+}
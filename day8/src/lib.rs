@@ -1,8 +1,14 @@
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
-use hashbrown::HashMap;
+pub use generate::{generate, INPUT_SIZE};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
+use aoc_math::lcm_all;
+use hashbrown::{HashMap, HashSet};
 
-use itertools::Itertools;
 use parser::parse_input;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -11,16 +17,6 @@ pub enum Move {
     Right,
 }
 
-impl From<char> for Move {
-    fn from(char: char) -> Self {
-        match char {
-            'L' => Move::Left,
-            'R' => Move::Right,
-            _ => panic!("Invalid move char"),
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Node<'a>(&'a str);
 
@@ -43,80 +39,91 @@ impl<'a> Map<'a> {
         }
     }
 
-    pub fn get(&self, node: &Node<'a>, r#move: Move) -> &Node<'a> {
-        let (left, right) = &self.nodes[node];
+    pub fn get(&self, node: &Node<'a>, r#move: Move) -> Result<&Node<'a>, SolveError> {
+        let (left, right) = self
+            .nodes
+            .get(node)
+            .ok_or_else(|| SolveError::not_found(format!("node {}", node.0)))?;
         match r#move {
-            Move::Left => left,
-            Move::Right => right,
+            Move::Left => Ok(left),
+            Move::Right => Ok(right),
         }
     }
 }
 
-pub fn solve_part_1(input: &str) -> u64 {
-    let map = parse_input(input);
-
-    let start_node = Node::new("AAA");
-    let end_node = Node::new("ZZZ");
-
-    let mut current_node = &start_node;
-    let mut num_moves = 0;
-    for r#move in map.moves.iter().cycle() {
-        let next_node = map.get(current_node, *r#move);
+/// Number of moves from `start`, beginning with the move at index `first_move`, to the first
+/// node that `is_end`, and that node.
+fn count_moves<'m, 'a>(
+    map: &'m Map<'a>,
+    start: &'m Node<'a>,
+    first_move: usize,
+    is_end: impl Fn(&Node<'_>) -> bool,
+) -> Result<(u64, &'m Node<'a>), SolveError> {
+    // Once every node was visited at every position in the moves, the walk repeats itself.
+    let max_moves = map.nodes.len() * map.moves.len();
+
+    let mut current_node = start;
+    let moves = map.moves.iter().cycle().skip(first_move).take(max_moves);
+    for (num_moves, r#move) in (1..).zip(moves) {
+        let next_node = map.get(current_node, *r#move)?;
         current_node = next_node;
-        num_moves += 1;
 
-        if current_node == &end_node {
-            break;
+        if is_end(current_node) {
+            return Ok((num_moves, current_node));
         }
     }
 
-    num_moves
+    Err(SolveError::not_found(format!("way from {} to the end", start.0)))
 }
 
-fn least_common_multiple(numbers: &[u64]) -> Option<u64> {
-    match numbers.len() {
-        0 => return None,
-        1 => return Some(numbers[0]),
-        _ => (),
-    }
+/// Number of moves the ghost from `start` takes to first stand on a node ending in `Z`.
+///
+/// The least common multiple of these is only the answer if every ghost then stands on such a
+/// node again after every as many moves and never in between, so this follows the ghost until
+/// it repeats itself to check that it does.
+fn ghost_period(map: &Map<'_>, start: &Node<'_>) -> Result<u64, SolveError> {
+    let is_end = |node: &Node<'_>| node.0.ends_with('Z');
+    let (period, mut node) = count_moves(map, start, 0, is_end)?;
+
+    let mut num_moves = period;
+    let mut seen = HashSet::new();
+    loop {
+        let first_move = (num_moves % map.moves.len() as u64) as usize;
+        if !seen.insert((node, first_move)) {
+            return Ok(period);
+        }
 
-    let a = numbers[0];
-    let b = least_common_multiple(&numbers[1..])?;
-    Some(a * b / greatest_common_divisor(a, b))
-}
+        let (next_period, next_node) = count_moves(map, node, first_move, is_end)?;
+        if next_period != period {
+            return Err(SolveError::not_found(format!(
+                "steady cycle to the end for the ghost from {}",
+                start.0
+            )));
+        }
 
-fn greatest_common_divisor(a: u64, b: u64) -> u64 {
-    match b {
-        0 => a,
-        _ => greatest_common_divisor(b, a % b),
+        num_moves += period;
+        node = next_node;
     }
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let map = parse_input(input);
+fn part_1(map: &Map<'_>) -> Result<u64, SolveError> {
+    let start_node = Node::new("AAA");
+    let end_node = Node::new("ZZZ");
 
+    count_moves(map, &start_node, 0, |node| node == &end_node).map(|(num_moves, _)| num_moves)
+}
+
+fn part_2(map: &Map<'_>) -> Result<u64, SolveError> {
     let num_moves = map
         .nodes
         .keys()
         .filter(|node| node.0.ends_with('A'))
-        .map(|node| {
-            let mut current_node = node;
-            let mut num_moves = 0u64;
-            for r#move in map.moves.iter().cycle() {
-                let next_node = map.get(current_node, *r#move);
-                current_node = next_node;
-                num_moves += 1;
-
-                if current_node.0.ends_with('Z'){
-                    break;
-                }
-            }
-
-            num_moves
-        })
-        .collect_vec();
-
-    least_common_multiple(&num_moves).unwrap()
+        .map(|node| ghost_period(map, node))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    lcm_all(&num_moves)
+        .and_then(|moves| u64::try_from(moves).ok())
+        .ok_or_else(|| SolveError::overflow("number of moves"))
 }
 
 // This was too slow :(
@@ -145,3 +152,29 @@ pub fn solve_part_2(input: &str) -> u64 {
 
 //     num_moves
 // }
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    part_1(&parse_input(input)?)
+}
+
+pub fn solve_part_2(input: &str) -> Result<u64, SolveError> {
+    part_2(&parse_input(input)?)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Map<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(map: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(map).map(Answer::from)
+    }
+
+    fn part_2(map: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(map).map(Answer::from)
+    }
+}
@@ -1,28 +1,39 @@
-use std::fs;
+use std::{env, process::ExitCode};
 
+use aoc_core::{input, Solution};
 use day8::*;
 
-fn main() {
-    let contents = fs::read_to_string("day8/input.txt").expect("Failed to read puzzle input");
+fn main() -> ExitCode {
+    let contents = input::load(env!("CARGO_MANIFEST_DIR"), env::args_os().nth(1))
+        .expect("Failed to read puzzle input");
+    let input = match Day8::parse(&contents) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    match Day8::part_1(&input) {
+        Ok(answer) => println!("Part 1: {answer}"),
+        Err(err) => eprintln!("Part 1: {}", err.in_input(&contents)),
+    }
+
+    match Day8::part_2(&input) {
+        Ok(answer) => println!("Part 2: {answer}"),
+        Err(err) => eprintln!("Part 2: {}", err.in_input(&contents)),
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    ExitCode::SUCCESS
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::SolveError;
 
-    const INPUT1: &str = "RL
+    use super::*;
 
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)";
+    const INPUT1: &str = include_str!("../example.txt");
 
     const INPUT2: &str = "LLR
 
@@ -30,30 +41,49 @@ AAA = (BBB, BBB)
 BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)";
 
-    const INPUT3: &str = "LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)";
+    const INPUT3: &str = include_str!("../example2.txt");
 
     #[test]
     fn test_part_1() {
         let sol1 = solve_part_1(INPUT1);
         let sol2 = solve_part_1(INPUT2);
 
-        assert_eq!(sol1, 2);
-        assert_eq!(sol2, 6);
+        assert_eq!(sol1, Ok(2));
+        assert_eq!(sol2, Ok(6));
     }
 
     #[test]
     fn test_part_2() {
         let sol = solve_part_2(INPUT3);
 
-        assert_eq!(sol, 6);
+        assert_eq!(sol, Ok(6));
+    }
+
+    #[test]
+    fn report_unreachable_end() {
+        assert_eq!(
+            solve_part_1("LR\n\nAAA = (BBB, BBB)\nZZZ = (ZZZ, ZZZ)"),
+            Err(SolveError::not_found("node BBB"))
+        );
+        assert_eq!(
+            solve_part_1("LR\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)"),
+            Err(SolveError::not_found("way from AAA to the end"))
+        );
+    }
+
+    #[test]
+    fn report_unsteady_ghost() {
+        // The ghost first reaches 11Z after two moves, but then loops through it every three.
+        let input = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11B, 11B)";
+
+        assert_eq!(
+            solve_part_2(input),
+            Err(SolveError::not_found("steady cycle to the end for the ghost from 11A"))
+        );
     }
 }
@@ -1,34 +1,35 @@
+use aoc_core::{
+    parse::{lines, parse_all},
+    ParseError,
+};
 use nom::{
-    character::complete::{char, line_ending, one_of, space1, alphanumeric1},
-    combinator::map,
-    multi::{many1, separated_list1},
+    branch::alt,
+    character::complete::{char, line_ending, space1, alphanumeric1},
+    combinator::{map, value},
+    multi::many1,
     sequence::{delimited, separated_pair, tuple},
-    Finish, IResult,
+    IResult,
 };
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) -> Map {
-    let (_, map) = map_document(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    map
+pub(crate) fn parse_input(input: &str) -> Result<Map<'_>, ParseError> {
+    parse_all(input, map_document)
 }
 
 fn map_move(input: &str) -> IResult<&str, Move> {
-    map(one_of("LR"), Move::from)(input)
+    alt((value(Move::Left, char('L')), value(Move::Right, char('R'))))(input)
 }
 
 fn map_moves(input: &str) -> IResult<&str, Vec<Move>> {
     many1(map_move)(input)
 }
 
-fn node(input: &str) -> IResult<&str, Node> {
+fn node(input: &str) -> IResult<&str, Node<'_>> {
     map(alphanumeric1, Node::new)(input)
 }
 
-fn map_entry(input: &str) -> IResult<&str, (Node, (Node, Node))> {
+fn map_entry(input: &str) -> IResult<&str, (Node<'_>, (Node<'_>, Node<'_>))> {
     separated_pair(
         node,
         tuple((space1, char('='), space1)),
@@ -40,12 +41,12 @@ fn map_entry(input: &str) -> IResult<&str, (Node, (Node, Node))> {
     )(input)
 }
 
-fn map_document(input: &str) -> IResult<&str, Map> {
+fn map_document(input: &str) -> IResult<&str, Map<'_>> {
     map(
         separated_pair(
             map_moves,
             many1(line_ending),
-            separated_list1(line_ending, map_entry),
+            lines(map_entry),
         ),
         |(moves, nodes)| Map::new(moves, nodes),
     )(input)
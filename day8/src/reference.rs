@@ -0,0 +1,84 @@
+//! Steps from walking the ghosts move by move instead of combining their cycles.
+//!
+//! Generated maps give every ghost a steady cycle like real inputs do, so these tests check
+//! the moves and the least common multiple but not maps without one, which `part_2` rejects.
+
+use std::collections::HashMap;
+
+/// Moves and the left and right neighbors of each node.
+fn network(input: &str) -> (&str, HashMap<&str, (&str, &str)>) {
+    let (moves, nodes) = input.split_once("\n\n").unwrap();
+    let nodes = nodes
+        .lines()
+        .map(|line| {
+            let (node, neighbors) = line.split_once(" = ").unwrap();
+            let neighbors = neighbors.trim_matches(['(', ')']);
+
+            (node, neighbors.split_once(", ").unwrap())
+        })
+        .collect();
+
+    (moves, nodes)
+}
+
+pub fn part_1(input: &str) -> u64 {
+    let (moves, nodes) = network(input);
+
+    let mut node = "AAA";
+    let mut steps = 0;
+    for r#move in moves.chars().cycle() {
+        let (left, right) = nodes[node];
+        node = if r#move == 'L' { left } else { right };
+        steps += 1;
+
+        if node == "ZZZ" {
+            break;
+        }
+    }
+
+    steps
+}
+
+/// Moves every ghost at once until they all stand on nodes ending in `Z`.
+pub fn part_2(input: &str) -> u64 {
+    let (moves, nodes) = network(input);
+
+    let mut ghosts = nodes
+        .keys()
+        .copied()
+        .filter(|node| node.ends_with('A'))
+        .collect::<Vec<_>>();
+    let mut steps = 0;
+    for r#move in moves.chars().cycle() {
+        for ghost in &mut ghosts {
+            let (left, right) = nodes[*ghost];
+            *ghost = if r#move == 'L' { left } else { right };
+        }
+        steps += 1;
+
+        if ghosts.iter().all(|ghost| ghost.ends_with('Z')) {
+            break;
+        }
+    }
+
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..10) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
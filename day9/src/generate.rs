@@ -0,0 +1,32 @@
+use aoc_gen::Rng;
+
+/// Size of the real puzzle input, as passed to [`generate`].
+pub const INPUT_SIZE: usize = 200;
+
+/// Number of values in each history.
+const HISTORY_LEN: i64 = 21;
+
+/// Generates `size` histories whose differences become all zero after anywhere from one row
+/// to all but the last, so that extrapolating them is exact at every degree it can handle.
+pub fn generate(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            // First value of each row of differences that is not all zero.
+            let mut firsts = (0..rng.gen_range(1..HISTORY_LEN))
+                .map(|_| rng.gen_range(-9..=9))
+                .collect::<Vec<i64>>();
+
+            (0..HISTORY_LEN)
+                .map(|_| {
+                    let value = firsts[0];
+                    for row in 0..firsts.len() - 1 {
+                        firsts[row] += firsts[row + 1];
+                    }
+                    value.to_string()
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
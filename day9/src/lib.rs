@@ -1,46 +1,101 @@
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
-use itertools::Itertools;
+pub use generate::{generate, INPUT_SIZE};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 use parser::parse_input;
 
-fn predict_next_value(values: &[i64]) -> i64 {
-    assert!(!values.is_empty());
+fn no_zero_differences(line: usize) -> SolveError {
+    SolveError::at_position(line, 0, "differences never become all zero")
+}
+
+fn overflow(line: usize) -> SolveError {
+    SolveError::overflow(format!("extrapolation of the history on line {}", line + 1))
+}
+
+/// Differences between consecutive values, or `None` if one does not fit in an `i64`.
+fn differences(values: &[i64]) -> Option<Vec<i64>> {
+    values.windows(2).map(|w| w[1].checked_sub(w[0])).collect()
+}
+
+/// Extrapolates the history on zero-based `line` of the report one value forward.
+fn predict_next_value(values: &[i64], line: usize) -> Result<i64, SolveError> {
+    let Some(&last) = values.last() else {
+        return Err(no_zero_differences(line));
+    };
 
     if values.iter().all(|&v| v == 0) {
-        return 0;
+        return Ok(0);
     }
 
-    let diffs = values.windows(2).map(|w| w[1] - w[0]).collect_vec();
-    values.last().unwrap() + predict_next_value(&diffs)
+    let diffs = differences(values).ok_or_else(|| overflow(line))?;
+    last.checked_add(predict_next_value(&diffs, line)?)
+        .ok_or_else(|| overflow(line))
 }
 
-pub fn solve_part_1(input: &str) -> i64 {
-    let report = parse_input(input);
+/// Extrapolates the history on zero-based `line` of the report one value backward.
+fn predict_previous_value(values: &[i64], line: usize) -> Result<i64, SolveError> {
+    let Some(&first) = values.first() else {
+        return Err(no_zero_differences(line));
+    };
 
+    if values.iter().all(|&v| v == 0) {
+        return Ok(0);
+    }
+
+    let diffs = differences(values).ok_or_else(|| overflow(line))?;
+    first.checked_sub(predict_previous_value(&diffs, line)?)
+        .ok_or_else(|| overflow(line))
+}
+
+fn sum_predictions(
+    report: &[Vec<i64>],
+    predict: fn(&[i64], usize) -> Result<i64, SolveError>,
+) -> Result<i64, SolveError> {
     report
-        .into_iter()
-        .map(|history| predict_next_value(&history))
-        .sum()
+        .iter()
+        .enumerate()
+        .try_fold(0i64, |sum, (line, history)| {
+            sum.checked_add(predict(history, line)?)
+                .ok_or_else(|| SolveError::overflow("sum of the predictions"))
+        })
 }
 
-fn predict_previous_value(values: &[i64]) -> i64 {
-    assert!(!values.is_empty());
+fn part_1(report: &[Vec<i64>]) -> Result<i64, SolveError> {
+    sum_predictions(report, predict_next_value)
+}
 
-    if values.iter().all(|&v| v == 0) {
-        return 0;
-    }
+fn part_2(report: &[Vec<i64>]) -> Result<i64, SolveError> {
+    sum_predictions(report, predict_previous_value)
+}
 
-    let diffs = values.windows(2).map(|w| w[1] - w[0]).collect_vec();
-    values.first().unwrap() - predict_previous_value(&diffs)
+pub fn solve_part_1(input: &str) -> Result<i64, SolveError> {
+    part_1(&parse_input(input)?)
 }
 
-pub fn solve_part_2(input: &str) -> i64 {
-    let report = parse_input(input);
+pub fn solve_part_2(input: &str) -> Result<i64, SolveError> {
+    part_2(&parse_input(input)?)
+}
 
-    report
-        .into_iter()
-        .map(|history| predict_previous_value(&history))
-        .sum()
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(report: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(report).map(Answer::from)
+    }
+
+    fn part_2(report: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(report).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -49,19 +104,43 @@ mod tests {
 
     #[test]
     fn test_predict_next_value() {
-        assert_eq!(predict_next_value(&[0, 0, 0]), 0);
-        assert_eq!(predict_next_value(&[3, 3, 3, 3]), 3);
-        assert_eq!(predict_next_value(&[0, 3, 6, 9, 12, 15]), 18);
-        assert_eq!(predict_next_value(&[1, 3, 6 ,10, 15, 21]), 28);
-        assert_eq!(predict_next_value(&[10, 13, 16, 21, 30, 45]), 68);
+        assert_eq!(predict_next_value(&[0, 0, 0], 0), Ok(0));
+        assert_eq!(predict_next_value(&[3, 3, 3, 3], 0), Ok(3));
+        assert_eq!(predict_next_value(&[0, 3, 6, 9, 12, 15], 0), Ok(18));
+        assert_eq!(predict_next_value(&[1, 3, 6 ,10, 15, 21], 0), Ok(28));
+        assert_eq!(predict_next_value(&[10, 13, 16, 21, 30, 45], 0), Ok(68));
     }
 
     #[test]
     fn test_predict_previous_value() {
-        assert_eq!(predict_previous_value(&[0, 0, 0]), 0);
-        assert_eq!(predict_previous_value(&[3, 3, 3, 3]), 3);
-        assert_eq!(predict_previous_value(&[0, 3, 6, 9, 12, 15]), -3);
-        assert_eq!(predict_previous_value(&[1, 3, 6 ,10, 15, 21]), 0);
-        assert_eq!(predict_previous_value(&[10, 13, 16, 21, 30, 45]), 5);
+        assert_eq!(predict_previous_value(&[0, 0, 0], 0), Ok(0));
+        assert_eq!(predict_previous_value(&[3, 3, 3, 3], 0), Ok(3));
+        assert_eq!(predict_previous_value(&[0, 3, 6, 9, 12, 15], 0), Ok(-3));
+        assert_eq!(predict_previous_value(&[1, 3, 6 ,10, 15, 21], 0), Ok(0));
+        assert_eq!(predict_previous_value(&[10, 13, 16, 21, 30, 45], 0), Ok(5));
+    }
+
+    #[test]
+    fn report_unpredictable_histories() {
+        let report = parse_input("0 3 6\n5").unwrap();
+        let error = part_1(&report).unwrap_err();
+        assert_eq!(error, SolveError::at_position(1, 0, "differences never become all zero"));
+        assert_eq!(
+            error.in_input("0 3 6\n5").to_string(),
+            "differences never become all zero at line 2, column 1\n  |\n2 | 5\n  | ^"
+        );
+
+        let report = parse_input("1 2 4").unwrap();
+        assert_eq!(part_2(&report), Err(no_zero_differences(0)));
+    }
+
+    #[test]
+    fn report_overflowing_differences() {
+        let report = parse_input("1 1\n9223372036854775807 -9223372036854775807").unwrap();
+
+        assert_eq!(
+            part_1(&report),
+            Err(SolveError::overflow("extrapolation of the history on line 2"))
+        );
     }
 }
@@ -1,17 +1,17 @@
+use aoc_core::{
+    parse::{lines, parse_all},
+    ParseError,
+};
 use nom::{
-    character::complete::{char, digit1, space1, line_ending},
+    character::complete::{char, digit1, space1},
     combinator::{map_res, opt},
     multi::separated_list1,
     sequence::tuple,
-    IResult, Finish,
+    IResult,
 };
 
-pub(crate) fn parse_input(input: &str) -> Vec<Vec<i64>> {
-    let (_, report) = oasis_report(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    report
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_all(input, oasis_report)
 }
 
 fn number(input: &str) -> IResult<&str, i64> {
@@ -25,7 +25,7 @@ fn value_history(input: &str) -> IResult<&str, Vec<i64>> {
 }
 
 fn oasis_report(input: &str) -> IResult<&str, Vec<Vec<i64>>> {
-    separated_list1(line_ending, value_history)(input)
+    lines(value_history)(input)
 }
 
 #[cfg(test)]
@@ -0,0 +1,69 @@
+//! Extrapolated histories from Lagrange interpolation instead of rows of differences.
+
+/// Value at `x` of the polynomial through the values of `history` at `0, 1, ...`, by Lagrange
+/// interpolation.
+fn interpolate(history: &[i64], x: i128) -> i64 {
+    let nodes = 0..history.len() as i128;
+
+    let value: i128 = nodes
+        .clone()
+        .zip(history)
+        .map(|(i, &y)| {
+            let (numerator, denominator) = nodes
+                .clone()
+                .filter(|&j| j != i)
+                .fold((1, 1), |(numerator, denominator), j| {
+                    (numerator * (x - j), denominator * (i - j))
+                });
+
+            // Every basis polynomial is an integer outside of the nodes.
+            y as i128 * (numerator / denominator)
+        })
+        .sum();
+
+    value as i64
+}
+
+fn histories(input: &str) -> Vec<Vec<i64>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .map(|value| value.parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+pub fn part_1(input: &str) -> i64 {
+    histories(input)
+        .iter()
+        .map(|history| interpolate(history, history.len() as i128))
+        .sum()
+}
+
+pub fn part_2(input: &str) -> i64 {
+    histories(input)
+        .iter()
+        .map(|history| interpolate(history, -1))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..20) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), Ok(part_1(&input)));
+            prop_assert_eq!(solve_part_2(&input), Ok(part_2(&input)));
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_core::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// Parsing may reject the input, but must never panic.
+fuzz_target!(|input: &str| {
+    let _ = day10::Day10::parse(input);
+});
@@ -1,11 +1,50 @@
+mod generate;
 mod parser;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
 
+pub use generate::{generate, INPUT_SIZE};
+
+use aoc_core::{Answer, ParseError, Solution, SolveError};
 use parser::parse_input;
 
-pub fn solve_part_1(input: &str) -> usize {
-    todo!()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub values: Vec<u64>,
+}
+
+// Reported as an error rather than left as `todo!()`, since the runner solves every
+// registered day as soon as it is generated.
+fn part_1(_entries: &[Entry]) -> Result<u64, SolveError> {
+    Err(SolveError::not_found("solution to part 1 yet"))
+}
+
+fn part_2(_entries: &[Entry]) -> Result<u64, SolveError> {
+    Err(SolveError::not_found("solution to part 2 yet"))
+}
+
+pub fn solve_part_1(input: &str) -> Result<u64, SolveError> {
+    part_1(&parse_input(input)?)
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    todo!()
+pub fn solve_part_2(input: &str) -> Result<u64, SolveError> {
+    part_2(&parse_input(input)?)
+}
+
+pub struct {{crate_name | upper_camel_case}};
+
+impl Solution for {{crate_name | upper_camel_case}} {
+    type Input<'a> = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(entries: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_1(entries).map(Answer::from)
+    }
+
+    fn part_2(entries: &Self::Input<'_>) -> Result<Answer, SolveError> {
+        part_2(entries).map(Answer::from)
+    }
 }
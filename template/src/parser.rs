@@ -1,10 +1,50 @@
-use nom::IResult;
+use aoc_core::{
+    parse::{lines, parse_all},
+    ParseError,
+};
+use nom::{
+    character::complete::{digit1, space1},
+    combinator::{map, map_res},
+    multi::separated_list1,
+    IResult,
+};
 
 use crate::*;
 
-pub(crate) fn parse_input(input: &str) {}
+pub(crate) fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parse_all(input, entries)
+}
+
+fn number(input: &str) -> IResult<&str, u64> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn entry(input: &str) -> IResult<&str, Entry> {
+    map(separated_list1(space1, number), |values| Entry { values })(input)
+}
+
+fn entries(input: &str) -> IResult<&str, Vec<Entry>> {
+    lines(entry)(input)
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn parse_entry() {
+        let input = "1 22 333";
+
+        let (rem, entry) = entry(input).unwrap();
+
+        assert_eq!(entry.values, vec![1, 22, 333]);
+        assert!(rem.is_empty());
+    }
+
+    #[test]
+    fn report_malformed_entry() {
+        let error = parse_input("1 2\nx 3\n").unwrap_err();
+
+        assert_eq!((error.line(), error.column()), (2, 1));
+    }
 }
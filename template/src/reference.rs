@@ -0,0 +1,31 @@
+//! Brute-force solutions to check the optimised ones against on generated inputs.
+
+use aoc_core::SolveError;
+
+pub fn part_1(_input: &str) -> Result<u64, SolveError> {
+    Err(SolveError::not_found("solution to part 1 yet"))
+}
+
+pub fn part_2(_input: &str) -> Result<u64, SolveError> {
+    Err(SolveError::not_found("solution to part 2 yet"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_gen::rng;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{generate, solve_part_1, solve_part_2};
+
+    proptest! {
+        #[test]
+        #[ignore]
+        fn matches_reference(seed in any::<u64>(), size in 1usize..20) {
+            let input = generate(size, &mut rng(seed));
+
+            prop_assert_eq!(solve_part_1(&input), part_1(&input));
+            prop_assert_eq!(solve_part_2(&input), part_2(&input));
+        }
+    }
+}